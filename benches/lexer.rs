@@ -0,0 +1,59 @@
+// 对 cases/compiler 下的所有 .ts 文件做词法分析, 统计吞吐量
+// 另外单独统计一个很长的单行文件
+// 运行: cargo bench --bench lexer
+
+use std::{fs, time::Instant};
+
+use kts::compiler::Compiler;
+
+const ROUNDS: usize = 10;
+
+fn main() {
+    let mut sources = Vec::new();
+    for entry in fs::read_dir("cases/compiler").expect("cases/compiler not found") {
+        let path = entry.unwrap().path();
+        if path.extension().is_some_and(|ext| ext == "ts") {
+            // 跳过非 UTF-8 编码的文件
+            if let Ok(src) = fs::read_to_string(&path) {
+                sources.push(src);
+            }
+        }
+    }
+
+    // 只统计能够通过词法分析的文件
+    sources.retain(|src| Compiler::lex(src).is_ok());
+    let bytes: usize = sources.iter().map(|src| src.len()).sum();
+    let mb = bytes as f64 / (1024.0 * 1024.0);
+
+    let mut best = f64::MAX;
+    let mut tokens = 0;
+    for _ in 0..ROUNDS {
+        let start = Instant::now();
+        tokens = sources.iter().map(|src| Compiler::lex(src).unwrap()).sum();
+        best = best.min(start.elapsed().as_secs_f64());
+    }
+
+    println!(
+        "lexed {} files, {:.2} MB, {} tokens in {:.3} s (best of {}): {:.2} MB/s",
+        sources.len(),
+        mb,
+        tokens,
+        best,
+        ROUNDS,
+        mb / best
+    );
+
+    // 压缩过的代码只有一行, 词法分析的耗时必须与行的长度成线性关系
+    let line = "let 变量 = f(a, \"s\") + 1; ".repeat(80_000);
+    let mb = line.len() as f64 / (1024.0 * 1024.0);
+    let start = Instant::now();
+    let tokens = Compiler::lex(&line).unwrap();
+    let elapsed = start.elapsed().as_secs_f64();
+    println!(
+        "lexed a single line of {:.2} MB, {} tokens in {:.3} s: {:.2} MB/s",
+        mb,
+        tokens,
+        elapsed,
+        mb / elapsed
+    );
+}
@@ -0,0 +1,4 @@
+`a${b}c${ `d${ e }f` }g`;
+tag`${ (() => { return h })() }`;
+`multi
+line ${ i } text`;
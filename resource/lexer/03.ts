@@ -0,0 +1,6 @@
+let re = /ab+c/gi;
+let half = a / 2 / b;
+let cls = /[/\]]+\//;
+if (/^\d{3}-\d{4}$/.test(phone)) {
+    count /= 2;
+}
@@ -0,0 +1,9 @@
+// 这是一个注释, 包含中文
+/* 多行注释
+   ünïcödé ✓ */
+let 名字 = "张三";
+let café = 'naïve résumé';
+let $el = "你好", _π = 3.14;
+let emoji = "\u{1F600} 😀 \x41\x42";
+let other = "\a\q\%\u0041\uD83D\uDE00";
+let abc = `模板 ${名字} 字符串`;
@@ -0,0 +1,19 @@
+/*
+ * Copyright (c) KTS contributors. 许可证头
+ */
+
+// @ts-ignore
+let a = 1; // 行尾注释
+let b = 2; /* trailing block */ /* another */
+
+/**
+ * 计算两个数的和
+ * 支持多行描述
+ * @param {number} x - 第一个数
+ * @param {{a: number}} [y=1] 第二个数
+ * @returns {number} 和
+ * @deprecated
+ */
+function add(x: number, y: number): number {
+    return x + y; /**/
+}
@@ -0,0 +1,2 @@
+a?.b; a?.[1]; a?.(); a ?? b; a ??= b; a?.5:b;
+a ** b; a **= b; a &&= b; a ||= b; a && b || c;
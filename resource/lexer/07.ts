@@ -0,0 +1,10 @@
+let million = 1_000_000;
+let mask = 0xFF_FF_FF_FF;
+let bits = 0b1010_0101;
+let perms = 0o7_5_5;
+let ratio = 1_000.000_1e1_0;
+let big = 123_456_789_012_345_678_901_234_567_890n;
+let hexBig = 0xFFFF_FFFF_FFFF_FFFF_FFFFn;
+let zero = 0n;
+let tiny = .5e-3;
+let trailing = 1.;
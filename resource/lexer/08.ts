@@ -0,0 +1,7 @@
+// 每一行都有一个词法错误, 错误之后的部分仍然可以继续扫描
+let a = "unterminated;
+let b = 'bad \x4G escape' + 1;
+let c = 0123;
+let d = a € b;
+let e = /unterminated regexp
+let f = `ok ${a} template`;
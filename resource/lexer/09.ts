@@ -0,0 +1,5 @@
+﻿#!/usr/bin/env node
+// BOM, hashbang, CRLF 以及 U+2028/U+2029 换行
+let a = 1;
+let b = `x
+y`; let c = a + b;let d = 'line \ continuation';
@@ -0,0 +1,6 @@
+const view = <ul class="list" data-n={`${n}`}>
+    {items.map(x => <li key={x}>{x / 2} items</li>)}
+    text with 'quotes' and /slashes/
+</ul>;
+const id = <T,>(x: T) => x;
+let y = a < b && c > d / 2;
@@ -0,0 +1,78 @@
+async function fetchUser(id: number): Promise<string> {
+    const res = await request(`/users/${id}`);
+    return await res.text();
+}
+
+async function load<T>(url: string);
+
+async function* pages(limit: number) {
+    let page = 0;
+    while (page < limit) {
+        yield await fetchPage(page++);
+    }
+}
+
+// await 和一元运算符同一优先级
+async function compute() {
+    const total = await a + await b;
+    const negated = !await check();
+    const nested = await await Promise.resolve(1);
+    const member = await client.get("key").value;
+    return typeof await value === "string";
+}
+
+const handler = async function () {
+    await sleep(10);
+};
+const named = async function* stream() {
+    yield 1;
+};
+
+const arrow = async (x: number) => await double(x);
+const single = async x => x + 1;
+const generic = async <T>(x: T): Promise<T> => x;
+const block = async () => {
+    await Promise.all([first(), second()]);
+};
+
+// 不是修饰符时 async 只是普通的标识符
+const async = 1;
+async(handler);
+
+class Service {
+    async start() {
+        await this.connect();
+    }
+
+    static async create() {
+        return new Service();
+    }
+
+    private async *events() {
+        yield await this.next();
+    }
+}
+
+// async 之后换行时是一个字段, 而不是方法的修饰符
+class Lazy {
+    async
+    load() {}
+}
+
+const api = {
+    async get(path: string) {
+        return await fetch(path);
+    },
+};
+
+for await (const chunk of stream) {
+    process(chunk);
+}
+
+for await (let [key, value] of entries) {
+    console.log(key, value);
+}
+
+// 模块顶层的 await
+const config = await loadConfig();
+await init(config);
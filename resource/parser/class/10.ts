@@ -0,0 +1,45 @@
+class Counter {
+    #count: number = 0;
+    static #instances = 0;
+    readonly #step;
+
+    static {
+        Counter.#instances = 0;
+    }
+
+    constructor(step: number) {
+        this.#step = step;
+        Counter.#instances++;
+    }
+
+    #increase(): void {
+        this.#count += this.#step;
+    }
+
+    get #value(): number {
+        return this.#count;
+    }
+
+    set #value(value) {
+        this.#count = value;
+    }
+
+    static isCounter(obj: any): boolean {
+        return #count in obj;
+    }
+
+    static {
+        let other = new Counter(1);
+        other?.#increase();
+    }
+}
+
+// 后面紧跟着 ( < = ; : ? 时 static async readonly 是成员名
+class Named {
+    async() {}
+    static() {}
+    readonly = 1;
+    static async(): void {}
+    static static: number;
+    async<T>(x: T) {}
+}
@@ -0,0 +1,7 @@
+let a = `hello`;
+let b = `a${b}c${d + e}f`;
+let c = `outer ${`inner ${x} end`} done`;
+let d = tag`line1
+line2 ${y}`;
+let e = obj.fmt`\`quoted\` \${not} ${z}`;
+let f = `${ (() => { if (g) { h } return i })() }!`;
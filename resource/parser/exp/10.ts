@@ -0,0 +1,4 @@
+let email = /^[\w.+-]+@[\w-]+\.[\w.]+$/i;
+let ratio = (a + b) / c / 2;
+let matched = /[a-z/]+/g.test(name) && x / y > 1;
+let r = typeof /x/ === "object" ? /"quoted"/ : /\\/;
@@ -0,0 +1,3 @@
+let 总数 = 数量 * 单价;
+let message = "你好, " + 名字 + "!\u{21}";
+let ᚠ = ɐ.ƀ(ℵ, "é\x20");
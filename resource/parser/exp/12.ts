@@ -0,0 +1,10 @@
+let port = config?.server?.port ?? 8080;
+let first = list?.[0]?.name;
+let result = callback?.(1, 2);
+let t = a?.5:b;
+let p = 2 ** 3 ** 2 * 4;
+let q = (-x) ** 2 + a ?? b;
+options.timeout ??= 1000;
+flag &&= ready;
+name ||= "anonymous";
+total **= 2;
@@ -0,0 +1,6 @@
+let a = 0x1F + 0o17 + 0b11;
+let b = 2147483647 + 2147483648;
+let c = 0xFFFFFFFF;
+let d = 1_000_000 * 1.5e3;
+let e = 10n ** 20n;
+let f = 0b1111_0000n;
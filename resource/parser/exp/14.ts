@@ -0,0 +1,44 @@
+let port = 80;
+let key = "host";
+let base = { debug: false };
+
+const cfg = {
+    port: 80,
+    "name": "kts",
+    1: "one",
+    [key + "name"]: "localhost",
+    [`${key}`]: 1,
+    port,
+    ...base,
+    delete: true,
+    get: 1,
+    async: 2,
+    get url(): string {
+        return this.name + ":" + this.port;
+    },
+    set url(value: string) {
+        this.name = value;
+    },
+    get() {
+        return 1;
+    },
+    toString(): string {
+        return "cfg";
+    },
+    async load(path: string) {
+        return path;
+    },
+    *items() {
+        yield 1;
+    },
+    [Symbol.iterator]() {
+        return this;
+    },
+    nested: { a: [], b: { } },
+};
+
+let empty = {};
+let fn = () => ({ port });
+{
+    label: port;
+}
@@ -0,0 +1,20 @@
+// 剩余参数和展开
+function sum(first: number, ...rest: number[]) {
+    return Math.max(first, ...rest);
+}
+
+function head(...[a, b]) {
+    return a;
+}
+
+class Logger {
+    log(level: string, ...messages: string[]): void {
+        console.log(level, ...messages, "end",);
+    }
+}
+
+const pass = (...args) => call(...args);
+const merged = [...left, 0, ...right.items];
+const copy = [...list];
+const inst = new Logger(...options);
+const [first, ...others] = merged;
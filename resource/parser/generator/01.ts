@@ -0,0 +1,58 @@
+function* counter(limit: number): Generator<number> {
+    let i = 0;
+    while (i < limit) {
+        yield i++;
+    }
+    return i;
+}
+
+function* delegate() {
+    yield* counter(3);
+    yield* [1, 2, 3];
+    yield;
+}
+
+// yield 也可以出现在其他表达式之中
+function* echo<T>(first: T) {
+    const received = yield first;
+    let pair = [yield 1, yield 2];
+    log(yield received);
+    const x = (yield) || 0;
+    return yield
+}
+
+const gen = function* () {
+    yield "anonymous";
+};
+const named = function* inner(...items: string[]) {
+    if (items.length) yield items[0];
+};
+
+class Tree<T> {
+    *iterate() {
+        yield* this.items;
+    }
+
+    static *range(from: number, to: number) {
+        while (from < to) {
+            yield from++;
+        }
+    }
+
+    private *walk() {
+        yield this;
+    }
+}
+
+const iterable = {
+    *values() {
+        yield 1;
+    },
+};
+
+// 三元表达式的分支中可以使用 yield
+function* choose(a: boolean, b: number) {
+    let c = a ? yield 1 : b;
+    let d = a ? b : yield 1;
+    let e = a ? yield : yield* other();
+}
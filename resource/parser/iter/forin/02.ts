@@ -0,0 +1,25 @@
+for (let key in obj) {
+    console.log(key, obj[key]);
+}
+
+for (const key in { a: 1, b: 2 }) total++;
+
+for (var name in window) {
+}
+
+// 表达式作为 for-in 的左侧
+for (target.key in source) {
+}
+
+for (cache[id] in table) {
+}
+
+// 括号中的 in 仍然是二元运算符
+for (let i = ("a" in obj) ? 1 : 0; i < 10; i++) {
+}
+
+for (let i = 0, found = keys.some(k => k in obj); i < n; i++) {
+}
+
+for (x = [a in b]; x; x = null) {
+}
@@ -0,0 +1,28 @@
+for (const [k, v] of map) {
+    console.log(k, v);
+}
+
+for (let { id, name } of users) {
+    register(id, name);
+}
+
+for (var item of [1, 2, 3]) sum += item;
+
+// 表达式作为 for-of 的左侧
+for (x of list) {
+}
+
+for (this.current of queue) {
+}
+
+for ([a, b] of pairs) {
+}
+
+for (const ch of "hello".split("")) {
+    if (ch in vowels) count++;
+}
+
+// of 在其他位置只是普通的标识符
+const of = 1;
+for (const of of values) {
+}
@@ -0,0 +1,30 @@
+let name = "kts";
+let items = ["a", "b"];
+let props = [];
+
+let hello = <div className="greeting" data-id='1' hidden>
+    Hello, {name}!
+    {/* comment */}
+    <br />
+    <Foo.Bar {...props} title={name + "!"} icon=<img src="a.png" /> />
+    <svg:rect xlink:href="#a"></svg:rect>
+</div>;
+
+let list = (
+    <>
+        <ul>{items.map(item => <li>{`item: ${item}`}</li>)}</ul>
+        {items.length > 1 && <p>many // items</p>}
+        a &gt; b
+    </>
+);
+
+let empty = <></>;
+
+// tsx 中的泛型箭头函数
+const first = <T,>(list: T[]) => list[0];
+const wrap = <T extends object>(value: T) => <div>{value}</div>;
+
+// 类型成员列表中的 <T> 是泛型调用签名, 不是 JSX
+interface Identity { <T>(x: T): T }
+type Pair = { <T>(x: T): T; <U>(y: U): U };
+let nested = <div>{{ a: <b /> }.a}</div>;
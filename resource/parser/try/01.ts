@@ -0,0 +1,51 @@
+function parse(text: string): number {
+    try {
+        return JSON.parse(text);
+    } catch (e) {
+        console.log(e);
+        throw e;
+    } finally {
+        cleanup();
+    }
+}
+
+try {
+    risky();
+} catch (error: unknown) {
+    throw new Error("failed: " + error);
+}
+
+// 省略 catch 参数
+try {
+    risky();
+} catch {
+    retry();
+}
+
+try {
+    open();
+} finally {
+    close();
+}
+
+// catch 参数也可以解构
+try {
+    request();
+} catch ({ message, code = 500 }) {
+    report(message, code);
+}
+
+// 嵌套
+try {
+    try {
+        inner();
+    } finally {
+        log("inner done");
+    }
+} catch ([first, ...rest]) {
+    throw { first, rest }
+}
+
+function fail(message: string): never {
+    throw new TypeError(message), message;
+}
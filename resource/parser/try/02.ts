@@ -0,0 +1,5 @@
+// throw 和表达式之间不能换行
+function fail() {
+    throw
+    new Error("x");
+}
@@ -0,0 +1,4 @@
+// try 之后必须有 catch 或 finally
+try {
+    risky();
+}
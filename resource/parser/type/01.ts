@@ -0,0 +1,48 @@
+// 泛型
+type Box<T> = { value: T };
+type Pair<K extends string, V = number> = Map<K, V>;
+type Nested = Array<Array<number>>;
+type Deep = Map<string, Array<Array<number>>>;
+type Items<T> = Array<T>[];
+
+interface List<T> extends Iterable<T>, Base<Array<T>> {
+    get(index: number): T;
+    map<U>(fn: (item: T) => U): List<U>;
+}
+
+class Stack<T, Store extends Array<T> = T[]> extends Base<T> implements List<T>, Sized {
+    items: Store;
+    push<K extends string, R>(item: T): void {
+        this.items.push(item);
+    }
+}
+
+function identity<T>(value: T): T {
+    return value;
+}
+
+const id = function <T>(value: T): T {
+    return value;
+};
+const arrow = <T, U extends T>(value: T, other: U) => value;
+
+let box: Box<Array<number>>= { value: [] };
+let n = identity<number>(1);
+let m = new Map<string, Array<number>>();
+let parsed = parse<{ a: number; b: string }>(text);
+let chained = factory.create<Stack<number>>().items;
+
+// 泛型函数类型
+type Identity = <T>(x: T) => T;
+let mapper: <T, U extends T>(list: T[], fn: (item: T) => U) => U[];
+interface Factory {
+    create: <T>() => T;
+}
+
+// 比较和移位
+let less = a < b;
+let both = a < b && c > d;
+let shifted = x >> 2;
+let compare = (a < b) > c;
+for (let i = 0; i < n; i++) {
+}
@@ -0,0 +1,43 @@
+// 联合, 交叉与字面量类型
+type Id = string | number;
+type Status = "ok" | "error" | 404 | -1 | true | null | undefined;
+type Named = { name: string } & { age: number } & Base;
+type Mixed = Id | Named & Tagged;
+type Choice =
+    | "left"
+    | "right";
+
+// 预定义类型
+let u: unknown;
+let nothing: never;
+let o: object;
+let big: bigint = 10n;
+
+// keyof 与索引访问
+type Keys = keyof Named;
+type NameType = Named["name"];
+type Values = Named[keyof Named];
+type Elem = Array<string>[number];
+
+// 括号与数组
+type Callbacks = (() => void)[];
+type MaybeList = (string | number)[];
+let grid: number[][] = [[1, 2], [3, 4]];
+let cube: Box<string>[][][];
+let names: readonly string[] = ["a"];
+
+// 元组
+type Point = [number, number];
+type Optional = [string, number?, boolean?];
+let frozen: readonly [number, string] = [1, "a"];
+let empty: [] = [];
+type Rest = [string, ...number[]];
+type Spread<T extends unknown[]> = [...T, boolean];
+
+function pick(obj: Named, key: keyof Named): Named[keyof Named] | undefined {
+    return undefined;
+}
+
+const handler = (event: string | null): void => {};
+let casted = value as string | number;
+let keys: Array<keyof Named> = [];
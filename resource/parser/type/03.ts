@@ -0,0 +1,28 @@
+// 条件类型与 infer
+type IsString<T> = T extends string ? true : false;
+type Unwrap<T> = T extends Promise<infer U> ? U : T;
+type ElementOf<T> = T extends (infer E)[] ? E : never;
+type ReturnOf<F> = F extends (...args: any[]) => infer R ? R : never;
+type Tail<T> = T extends [infer H, ...infer R] ? R : never;
+type Kind<T> = T extends string
+    ? "string"
+    : T extends number
+    ? "number"
+    : "other";
+
+// 映射类型
+type Partial<T> = { [K in keyof T]?: T[K] };
+type Readonly<T> = { readonly [P in keyof T]: T[P] };
+type Mutable<T> = { -readonly [P in keyof T]-?: T[P] };
+type Frozen<T> = { +readonly [P in keyof T]+?: T[P]; };
+type Getters<T> = { [K in keyof T as `get${Capitalize<K>}`]: () => T[K] };
+type Flags<Keys extends string> = { [K in Keys]: boolean };
+
+// 模板字面量类型
+type Plain = `plain`;
+type Prefixed = `prefix-${string}`;
+type EventName<T extends string> = `${T}Changed` | `${T}-${number}-end`;
+
+// infer 不是关键字时仍然可以作为普通的类型名
+type infer = number;
+let value: infer = 1;
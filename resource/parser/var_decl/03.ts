@@ -0,0 +1,36 @@
+// 上下文关键字可以作为标识符使用
+let type = 1;
+let from = "a", as = 2, get = 3;
+var namespace = type + as;
+let module = [type, from];
+let async = (declare: number, readonly: string) => declare;
+let readonly = 1;
+readonly = readonly + 1;
+
+type = get * 2;
+namespace = type / as;
+declare = obj.get(from).set(1);
+obj.delete(type).class;
+obj?.get;
+
+function is(string: any, number: any) {
+    return string;
+}
+
+type Alias = number;
+
+class Map {
+    get: number;
+    type(): string {
+        return this.get;
+    }
+    get size(): number {
+        return 0;
+    }
+}
+
+interface Options {
+    readonly type: string;
+    from: number;
+    delete(): void;
+}
@@ -0,0 +1,30 @@
+// 解构
+const { a, b = 2, ...rest } = props;
+const { name: alias, info: { age, tags: [first, , third = "c"] } } = user;
+const { [key]: computed, "quoted": q, 0: zero } = obj;
+let [x, y] = [1, 2];
+let [, second, ...others] = list;
+var [[m, n] = [0, 0], { p }] = pairs, plain = 1;
+
+function swap({ left, right }: Pair, [head, ...tail]: number[], { flag } = defaults) {
+    return [right, left];
+}
+
+const pick = ({ id, value = 0 }, [k]) => id;
+const single = ([h]) => h;
+
+[x, y] = [y, x];
+[this.first, obj.list[0], ...rest.items] = values;
+({ a, b: { c = 1 }, ...rest } = props);
+
+for (let [k, v] = entry; k < v; k++) {
+}
+
+for ([k, v] in map) {
+}
+
+class Point {
+    move({ dx = 0, dy = 0 }: Delta, [scale]: number[] = [1]) {
+        return dx * scale;
+    }
+}
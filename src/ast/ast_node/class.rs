@@ -86,6 +86,19 @@ pub enum ClassElement {
     ConstructorDecl(ConstructorDecl),
     PropertyMemberDecl(PropertyMemberDecl),
     IndexMemberDecl(IndexMemberDecl),
+    StaticBlock(StaticBlock),
+}
+
+// static { ... } 类的静态初始化块
+#[derive(Visualizable, Default)]
+pub struct StaticBlock {
+    func_body: ASTNode<FuncBody>,
+}
+
+impl StaticBlock {
+    pub(crate) fn set_func_body(&mut self, func_body: ASTNode<FuncBody>) {
+        self.func_body = func_body;
+    }
 }
 
 #[derive(Visualizable, Default)]
@@ -162,6 +175,7 @@ pub struct MethodDeclExp {
     access_modifier: Option<ASTNode<AccessModifier>>,
     static_: Option<KeyWordKind>,
     async_: Option<KeyWordKind>,
+    generator: Option<TokenKind>,
     identifier: ASTNode<Identifier>,
     call_signature: ASTNode<CallSig>,
     func_body: Option<ASTNode<FuncBody>>,
@@ -180,6 +194,10 @@ impl MethodDeclExp {
         self.async_ = Some(KeyWordKind::Async);
     }
 
+    pub(crate) fn set_generator(&mut self) {
+        self.generator = Some(TokenKind::Multiply);
+    }
+
     pub(crate) fn set_identifier(&mut self, identifier: ASTNode<Identifier>) {
         self.identifier = identifier;
     }
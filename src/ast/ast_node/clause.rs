@@ -2,7 +2,10 @@ use crate::ast::visulize::AstGraph;
 use crate::ast::visulize::Visualizable;
 use crate::ast::{ASTNode, NodeInfo};
 
+use super::block::Block;
 use super::exp::Exp;
+use super::parameter::TypeAnnotation;
+use super::pattern::BindingTarget;
 use super::source_element::SourceElements;
 
 #[derive(Visualizable, Default)]
@@ -35,3 +38,34 @@ impl DefaultClause {
         Self { stats }
     }
 }
+
+// catch (e: unknown) { ... }, 其中的参数可以省略
+#[derive(Visualizable, Default)]
+pub struct CatchClause {
+    catch_para: Option<ASTNode<BindingTarget>>,
+    type_annotation: Option<ASTNode<TypeAnnotation>>,
+    block: ASTNode<Block>,
+}
+impl CatchClause {
+    pub(crate) fn set_catch_para(&mut self, catch_para: ASTNode<BindingTarget>) {
+        self.catch_para = Some(catch_para);
+    }
+
+    pub(crate) fn set_type_annotation(&mut self, type_annotation: ASTNode<TypeAnnotation>) {
+        self.type_annotation = Some(type_annotation);
+    }
+
+    pub(crate) fn set_block(&mut self, block: ASTNode<Block>) {
+        self.block = block;
+    }
+}
+
+#[derive(Visualizable)]
+pub struct FinallyClause {
+    block: ASTNode<Block>,
+}
+impl FinallyClause {
+    pub(crate) fn new(block: ASTNode<Block>) -> Self {
+        Self { block }
+    }
+}
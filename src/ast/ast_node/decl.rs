@@ -17,6 +17,7 @@ use super::{
 
 #[derive(Visualizable, Default)]
 pub struct FuncDecl {
+    async_: Option<KeyWordKind>,
     func_name: ASTNode<Identifier>,
     call_sig: ASTNode<CallSig>,
     func_body: Option<ASTNode<FuncBody>>,
@@ -28,11 +29,16 @@ impl FuncDecl {
         func_body: Option<ASTNode<FuncBody>>,
     ) -> Self {
         Self {
+            async_: None,
             func_name,
             call_sig,
             func_body,
         }
     }
+
+    pub(crate) fn set_async(&mut self) {
+        self.async_ = Some(KeyWordKind::Async);
+    }
 }
 
 #[derive(Visualizable, Default)]
@@ -179,16 +185,26 @@ impl AbsVar {
 
 #[derive(Visualizable, Default)]
 pub struct FuncExpDecl {
+    async_: Option<KeyWordKind>,
     func_name: Option<ASTNode<Identifier>>,
+    type_paras: Option<ASTNode<TypeParas>>,
     formal_paras: Option<ASTNode<FormalParas>>,
     type_annotation: Option<ASTNode<TypeAnnotation>>,
     func_body: ASTNode<FuncBody>,
 }
 impl FuncExpDecl {
+    pub(crate) fn set_async(&mut self) {
+        self.async_ = Some(KeyWordKind::Async);
+    }
+
     pub(crate) fn set_func_name(&mut self, func_name: ASTNode<Identifier>) {
         self.func_name = Some(func_name);
     }
 
+    pub(crate) fn set_type_paras(&mut self, type_paras: ASTNode<TypeParas>) {
+        self.type_paras = Some(type_paras);
+    }
+
     pub(crate) fn set_formal_paras(&mut self, formal_paras: ASTNode<FormalParas>) {
         self.formal_paras = Some(formal_paras);
     }
@@ -230,6 +246,7 @@ impl ClassExp {
 #[derive(Visualizable, Default)]
 pub struct ArrowFuncExpDecl {
     async_: Option<KeyWordKind>,
+    type_paras: Option<ASTNode<TypeParas>>,
     formal_paras: ASTNode<FormalParas>,
     type_annotation: Option<ASTNode<TypeAnnotation>>,
     func_body: ASTNode<ArrowFuncBody>,
@@ -239,6 +256,11 @@ impl ArrowFuncExpDecl {
         self.async_ = Some(KeyWordKind::Async);
     }
 
+    // <T>(a: T) => ...
+    pub(crate) fn set_type_paras(&mut self, type_paras: ASTNode<TypeParas>) {
+        self.type_paras = Some(type_paras);
+    }
+
     // (a, b, c) => ...
     pub(crate) fn set_formal_paras(&mut self, formal_paras: ASTNode<FormalParas>) {
         self.formal_paras = formal_paras;
@@ -265,8 +287,41 @@ impl Default for ArrowFuncBody {
     }
 }
 
+// 生成器函数, 作为表达式时函数名可以省略
 #[derive(Visualizable, Default)]
-pub struct GenFuncDecl {}
+pub struct GenFuncDecl {
+    async_: Option<KeyWordKind>,
+    func_name: Option<ASTNode<Identifier>>,
+    type_paras: Option<ASTNode<TypeParas>>,
+    formal_paras: Option<ASTNode<FormalParas>>,
+    type_annotation: Option<ASTNode<TypeAnnotation>>,
+    func_body: ASTNode<FuncBody>,
+}
+impl GenFuncDecl {
+    pub(crate) fn set_async(&mut self) {
+        self.async_ = Some(KeyWordKind::Async);
+    }
+
+    pub(crate) fn set_func_name(&mut self, func_name: ASTNode<Identifier>) {
+        self.func_name = Some(func_name);
+    }
+
+    pub(crate) fn set_type_paras(&mut self, type_paras: ASTNode<TypeParas>) {
+        self.type_paras = Some(type_paras);
+    }
+
+    pub(crate) fn set_formal_paras(&mut self, formal_paras: ASTNode<FormalParas>) {
+        self.formal_paras = Some(formal_paras);
+    }
+
+    pub(crate) fn set_type_annotation(&mut self, type_annotation: ASTNode<TypeAnnotation>) {
+        self.type_annotation = Some(type_annotation);
+    }
+
+    pub(crate) fn set_func_body(&mut self, func_body: ASTNode<FuncBody>) {
+        self.func_body = func_body;
+    }
+}
 
 #[derive(Visualizable, Default)]
 pub struct NamespaceName {
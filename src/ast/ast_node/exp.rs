@@ -1,9 +1,13 @@
 use std::cmp::{Ordering, PartialOrd};
 use std::collections::HashMap;
 
-use super::decl::{ArrowFuncExpDecl, ClassExp, FuncExpDecl, NamespaceName};
+use super::class::Accesser;
+use super::decl::{ArrowFuncExpDecl, ClassExp, FuncBody, FuncExpDecl, GenFuncDecl, NamespaceName};
 use super::identifier::Identifier;
+use super::jsx::{JsxElement, JsxFragment};
 use super::literal::Literal;
+use super::pattern::BindingPattern;
+use super::sig::CallSig;
 use super::type_::{Type, TypeArgs};
 use crate::ast::{Span, Visualizable, AST};
 
@@ -41,10 +45,15 @@ lazy_static! {
         map.insert(Op::LeftShiftArithmeticAssign, (21, 20));     // <<=
         map.insert(Op::RightShiftArithmeticAssign, (21, 20));  // >>=
         map.insert(Op::RightShiftLogicalAssign, (21, 20)); // >>>=
+        map.insert(Op::PowerAssign, (21, 20));         // **=
+        map.insert(Op::NullCoalesceAssign, (21, 20));  // ??=
+        map.insert(Op::AndAssign, (21, 20));           // &&=
+        map.insert(Op::OrAssign, (21, 20));            // ||=
 
         map.insert(Op::QuestionMark, (30, 31));            // ? :
         map.insert(Op::Colon, (30, 31));            // ? :
 
+        map.insert(Op::NullCoalesce, (35, 36));              // ??
         map.insert(Op::Or, (40, 41));                        // ||
         map.insert(Op::And, (50, 51));                       // &&
         map.insert(Op::BitOr, (60, 61));                        // |
@@ -76,6 +85,9 @@ lazy_static! {
         map.insert(Op::Divide, (130, 131));                        // /
         map.insert(Op::Mod, (130, 131));                        // %
 
+        // ** 是右结合的
+        map.insert(Op::Power, (141, 140));                        // **
+
         // 右结合
         map.insert(Op::Delete, (151, 150));                        // delete
         map.insert(Op::Typeof, (151, 150));                        // typeof
@@ -94,6 +106,9 @@ lazy_static! {
         map.insert(Op::Dot, (180, 181));                        // .
         map.insert(Op::Index, (180, 181));                        // []
         map.insert(Op::Call, (180, 181));              //  ()
+        map.insert(Op::OptionalDot, (180, 181));       // ?.
+        map.insert(Op::OptionalIndex, (180, 181));     // ?.[]
+        map.insert(Op::OptionalCall, (180, 181));      // ?.()
 
         map
     };
@@ -120,6 +135,11 @@ pub enum Op {
     Index,  // []
     Call,   // ()
 
+    OptionalDot,   // ?.
+    OptionalIndex, // ?.[]
+    OptionalCall,  // ?.()
+
+    Power,    // **
     Multiply, // *
     Divide,   // /
     Mod,      // %
@@ -138,6 +158,11 @@ pub enum Op {
     BitOr,        // |
     Or,           // ||
     BitOrAssign,  // |=
+    AndAssign,    // &&=
+    OrAssign,     // ||=
+
+    NullCoalesce,       // ??
+    NullCoalesceAssign, // ??=
 
     Instanceof, //  instanceof
     In,         // in
@@ -149,6 +174,7 @@ pub enum Op {
     Equals,    // ==
     NotEquals, // !=
 
+    PowerAssign,    // **=
     MultiplyAssign, // *=
     DivideAssign,   // /=
     ModulusAssign,  // %=
@@ -228,6 +254,15 @@ impl Visualizable for Op {
             Op::Dot => graph.put_node(self_info, "."),
             Op::Index => graph.put_node(self_info, "[]"),
             Op::Call => graph.put_node(self_info, "call"),
+            Op::OptionalDot => graph.put_node(self_info, "?."),
+            Op::OptionalIndex => graph.put_node(self_info, "?.[]"),
+            Op::OptionalCall => graph.put_node(self_info, "?.call"),
+            Op::Power => graph.put_node(self_info, "**"),
+            Op::PowerAssign => graph.put_node(self_info, "**="),
+            Op::AndAssign => graph.put_node(self_info, "&&="),
+            Op::OrAssign => graph.put_node(self_info, "||="),
+            Op::NullCoalesce => graph.put_node(self_info, "??"),
+            Op::NullCoalesceAssign => graph.put_node(self_info, "??="),
             Op::Multiply => graph.put_node(self_info, "*"),
             Op::Divide => graph.put_node(self_info, "/"),
             Op::Mod => graph.put_node(self_info, "%"),
@@ -281,6 +316,7 @@ impl PartialOrd for Op {
 }
 
 #[derive(Visualizable)]
+#[allow(clippy::enum_variant_names)]
 pub enum Exp {
     UnaryExp(UnaryExp),
     BinaryExp(BinaryExp),
@@ -293,6 +329,8 @@ pub enum Exp {
     ArgsExp(ArgsExp),
 
     FunctionExp(FuncExpDecl),
+    // function* () {}
+    GeneratorExp(GenFuncDecl),
     ClassExp(ClassExp),
 
     ArrowFuncExp(ArrowFuncExpDecl),
@@ -303,6 +341,11 @@ pub enum Exp {
     // 单个字面量，如 1, "abc"
     Literal(Literal),
 
+    // 模板字符串, 如 `a${b}c`
+    TemplateExp(TemplateExp),
+    // 带标签的模板字符串, 如 tag`a${b}c`
+    TaggedTemplateExp(TaggedTemplateExp),
+
     // this
     This(KeyWordKind),
     // super
@@ -313,6 +356,21 @@ pub enum Exp {
 
     // 数组
     ArrayExp(ArrayExp),
+    // 对象字面量, 如 { a: 1, b }
+    ObjectLiteral(ObjectLiteral),
+    // 解构赋值的左侧, 如 [a, b] = [b, a]
+    BindingPattern(BindingPattern),
+    // 函数参数和数组中的展开, 如 f(...args), [...a, b]
+    SpreadElement(SpreadElement),
+    // yield, yield x, yield* iter
+    YieldExp(YieldExp),
+    // await x, 和一元运算符同一优先级
+    AwaitExp(AwaitExp),
+
+    // <div>...</div>
+    JsxElement(JsxElement),
+    // <>...</>
+    JsxFragment(JsxFragment),
 }
 
 pub struct UnaryExp {
@@ -324,6 +382,10 @@ impl UnaryExp {
     pub fn new(op: Op, exp: ASTNode<Exp>) -> Self {
         Self { op, exp }
     }
+
+    pub(crate) fn get_op(&self) -> &Op {
+        &self.op
+    }
 }
 
 // 因为要区分前置和后置，手动实现 Visualizable
@@ -415,15 +477,22 @@ impl GroupExp {
 
 #[derive(Visualizable, Default)]
 pub struct ArgsExp {
+    // f<number>(x)
+    type_args: Option<ASTNode<TypeArgs>>,
     args: Vec<ASTNode<Exp>>,
 }
 
 impl ArgsExp {
     pub(crate) fn new(args: ASTNode<ExpSeq>) -> Self {
         Self {
+            type_args: None,
             args: args.ctx().exps,
         }
     }
+
+    pub(crate) fn set_type_args(&mut self, type_args: ASTNode<TypeArgs>) {
+        self.type_args = Some(type_args);
+    }
 }
 
 #[derive(Visualizable, Default)]
@@ -467,3 +536,204 @@ impl ArrayExp {
         self.array_elements.push(array_element);
     }
 }
+
+#[derive(Visualizable)]
+pub struct SpreadElement {
+    exp: ASTNode<Exp>,
+}
+
+impl SpreadElement {
+    pub(crate) fn new(exp: ASTNode<Exp>) -> Self {
+        Self { exp }
+    }
+}
+
+// yield* 会把迭代交给另一个可迭代对象
+#[derive(Visualizable, Default)]
+pub struct YieldExp {
+    delegate: Option<TokenKind>,
+    exp: Option<ASTNode<Exp>>,
+}
+
+impl YieldExp {
+    pub(crate) fn set_delegate(&mut self) {
+        self.delegate = Some(TokenKind::Multiply);
+    }
+
+    pub(crate) fn set_exp(&mut self, exp: ASTNode<Exp>) {
+        self.exp = Some(exp);
+    }
+}
+
+#[derive(Visualizable)]
+pub struct AwaitExp {
+    exp: ASTNode<Exp>,
+}
+
+impl AwaitExp {
+    pub(crate) fn new(exp: ASTNode<Exp>) -> Self {
+        Self { exp }
+    }
+}
+
+#[derive(Visualizable, Default)]
+pub struct ObjectLiteral {
+    property_assignments: Vec<ASTNode<PropertyAssignment>>,
+}
+
+impl ObjectLiteral {
+    pub(crate) fn push_property_assignment(
+        &mut self,
+        property_assignment: ASTNode<PropertyAssignment>,
+    ) {
+        self.property_assignments.push(property_assignment);
+    }
+}
+
+#[derive(Visualizable)]
+pub enum PropertyAssignment {
+    // a: 1, "b": 2, [c]: 3
+    PropertyExpAssignment(PropertyExpAssignment),
+    // { a } 即 { a: a }
+    PropertyShorthand(PropertyShorthand),
+    // a() {}
+    MethodProperty(MethodProperty),
+    // get a() {}, set a(v) {}
+    Accesser(Accesser),
+    // ...a
+    SpreadProperty(SpreadProperty),
+}
+
+#[derive(Visualizable)]
+pub struct PropertyExpAssignment {
+    property_name: ASTNode<PropertyName>,
+    exp: ASTNode<Exp>,
+}
+
+impl PropertyExpAssignment {
+    pub(crate) fn new(property_name: ASTNode<PropertyName>, exp: ASTNode<Exp>) -> Self {
+        Self { property_name, exp }
+    }
+}
+
+#[derive(Visualizable)]
+pub struct PropertyShorthand {
+    identifier: ASTNode<Identifier>,
+}
+
+impl PropertyShorthand {
+    pub(crate) fn new(identifier: ASTNode<Identifier>) -> Self {
+        Self { identifier }
+    }
+}
+
+#[derive(Visualizable, Default)]
+pub struct MethodProperty {
+    async_: Option<KeyWordKind>,
+    generator: Option<TokenKind>,
+    property_name: ASTNode<PropertyName>,
+    call_sig: ASTNode<CallSig>,
+    func_body: ASTNode<FuncBody>,
+}
+
+impl MethodProperty {
+    pub(crate) fn set_async(&mut self) {
+        self.async_ = Some(KeyWordKind::Async);
+    }
+
+    pub(crate) fn set_generator(&mut self) {
+        self.generator = Some(TokenKind::Multiply);
+    }
+
+    pub(crate) fn set_property_name(&mut self, property_name: ASTNode<PropertyName>) {
+        self.property_name = property_name;
+    }
+
+    pub(crate) fn set_call_sig(&mut self, call_sig: ASTNode<CallSig>) {
+        self.call_sig = call_sig;
+    }
+
+    pub(crate) fn set_func_body(&mut self, func_body: ASTNode<FuncBody>) {
+        self.func_body = func_body;
+    }
+}
+
+#[derive(Visualizable)]
+pub struct SpreadProperty {
+    exp: ASTNode<Exp>,
+}
+
+impl SpreadProperty {
+    pub(crate) fn new(exp: ASTNode<Exp>) -> Self {
+        Self { exp }
+    }
+}
+
+// 属性名可以是任意标识符或关键字, 字符串, 数字, 或者计算属性名 [exp]
+#[derive(Visualizable)]
+pub enum PropertyName {
+    Identifier(Identifier),
+    Literal(Literal),
+    ComputedPropertyName(ComputedPropertyName),
+}
+
+impl Default for PropertyName {
+    fn default() -> Self {
+        PropertyName::Identifier(Identifier::default())
+    }
+}
+
+#[derive(Visualizable)]
+pub struct ComputedPropertyName {
+    exp: ASTNode<Exp>,
+}
+
+impl ComputedPropertyName {
+    pub(crate) fn new(exp: ASTNode<Exp>) -> Self {
+        Self { exp }
+    }
+}
+
+/*
+`head${exp}literal${exp}literal`
+head 和每个 span 的 literal 都是已处理转义的字符串
+*/
+#[derive(Visualizable, Default)]
+pub struct TemplateExp {
+    head: ASTNode<Literal>,
+    template_spans: Vec<ASTNode<TemplateSpan>>,
+}
+
+impl TemplateExp {
+    pub(crate) fn set_head(&mut self, head: ASTNode<Literal>) {
+        self.head = head;
+    }
+
+    pub(crate) fn push_template_span(&mut self, template_span: ASTNode<TemplateSpan>) {
+        self.template_spans.push(template_span);
+    }
+}
+
+#[derive(Visualizable)]
+pub struct TemplateSpan {
+    exp: ASTNode<Exp>,
+    literal: ASTNode<Literal>,
+}
+
+impl TemplateSpan {
+    pub(crate) fn new(exp: ASTNode<Exp>, literal: ASTNode<Literal>) -> Self {
+        Self { exp, literal }
+    }
+}
+
+#[derive(Visualizable)]
+pub struct TaggedTemplateExp {
+    tag: ASTNode<Exp>,
+    template: ASTNode<TemplateExp>,
+}
+
+impl TaggedTemplateExp {
+    pub(crate) fn new(tag: ASTNode<Exp>, template: ASTNode<TemplateExp>) -> Self {
+        Self { tag, template }
+    }
+}
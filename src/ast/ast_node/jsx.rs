@@ -0,0 +1,108 @@
+use crate::ast::visulize::AstGraph;
+use crate::ast::{ASTNode, NodeInfo, Visualizable};
+
+use super::exp::Exp;
+use super::identifier::Identifier;
+use super::literal::Literal;
+
+// <div id="a">...</div> 或者自闭合的 <div />
+#[derive(Visualizable, Default)]
+pub struct JsxElement {
+    // 标签名可以是 a, Foo.Bar, svg:rect
+    name: ASTNode<Identifier>,
+    attributes: Vec<ASTNode<JsxAttr>>,
+    children: Vec<ASTNode<JsxChild>>,
+}
+
+impl JsxElement {
+    pub(crate) fn set_name(&mut self, name: ASTNode<Identifier>) {
+        self.name = name;
+    }
+
+    pub(crate) fn push_attribute(&mut self, attribute: ASTNode<JsxAttr>) {
+        self.attributes.push(attribute);
+    }
+
+    pub(crate) fn push_child(&mut self, child: ASTNode<JsxChild>) {
+        self.children.push(child);
+    }
+}
+
+// <>...</>
+#[derive(Visualizable, Default)]
+pub struct JsxFragment {
+    children: Vec<ASTNode<JsxChild>>,
+}
+
+impl JsxFragment {
+    pub(crate) fn push_child(&mut self, child: ASTNode<JsxChild>) {
+        self.children.push(child);
+    }
+}
+
+#[derive(Visualizable)]
+pub enum JsxAttr {
+    Attribute(JsxAttribute),
+    SpreadAttribute(JsxSpreadAttribute),
+}
+
+// name 或者 name=value
+#[derive(Visualizable, Default)]
+pub struct JsxAttribute {
+    name: ASTNode<Identifier>,
+    value: Option<ASTNode<JsxAttrValue>>,
+}
+
+impl JsxAttribute {
+    pub(crate) fn set_name(&mut self, name: ASTNode<Identifier>) {
+        self.name = name;
+    }
+
+    pub(crate) fn set_value(&mut self, value: ASTNode<JsxAttrValue>) {
+        self.value = Some(value);
+    }
+}
+
+#[derive(Visualizable)]
+pub enum JsxAttrValue {
+    String(Literal),
+    ExpContainer(JsxExpContainer),
+    Element(JsxElement),
+    Fragment(JsxFragment),
+}
+
+// {...props}
+#[derive(Visualizable)]
+pub struct JsxSpreadAttribute {
+    exp: ASTNode<Exp>,
+}
+
+impl JsxSpreadAttribute {
+    pub(crate) fn new(exp: ASTNode<Exp>) -> Self {
+        Self { exp }
+    }
+}
+
+#[derive(Visualizable)]
+pub enum JsxChild {
+    Text(Literal),
+    ExpContainer(JsxExpContainer),
+    Element(JsxElement),
+    Fragment(JsxFragment),
+}
+
+// {exp}, 其中只有注释时 exp 为空, 例如 {/* comment */}
+#[derive(Visualizable, Default)]
+pub struct JsxExpContainer {
+    exp: Option<ASTNode<Exp>>,
+}
+
+impl JsxExpContainer {
+    pub(crate) fn set_exp(&mut self, exp: ASTNode<Exp>) {
+        self.exp = Some(exp);
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.exp.is_none()
+    }
+}
@@ -4,13 +4,79 @@ use crate::ast::{visulize::Visualizable, AstGraph, NodeInfo};
 pub enum Literal {
     Number(f64),
     Integer(i32),
+    // 十进制表示的 BigInt
+    BigInt(String),
     String(String),
     Boolean(bool),
+    RegExp {
+        pattern: String,
+        flags: String,
+    },
 
     #[default]
     Null,
 }
 
+impl Literal {
+    /*
+    由 Lexer 产生的数字 token (已去掉分隔符) 计算数值:
+        不含小数点和指数的整数在 i32 范围内时为 Integer, 否则为 Number
+    */
+    pub(crate) fn from_number(number: &str) -> Literal {
+        let (radix, digits) = split_radix(number);
+        if radix == 10 && number.contains(['.', 'e', 'E']) {
+            return Literal::Number(number.parse().unwrap());
+        }
+
+        match i32::from_str_radix(digits, radix) {
+            Ok(integer) => Literal::Integer(integer),
+            Err(_) => match (radix, u128::from_str_radix(digits, radix)) {
+                (10, _) => Literal::Number(digits.parse().unwrap()),
+                (_, Ok(integer)) => Literal::Number(integer as f64),
+                // 超出 u128 的其他进制整数
+                (_, Err(_)) => Literal::Number(digits.chars().fold(0.0, |value, c| {
+                    value * radix as f64 + c.to_digit(radix).unwrap() as f64
+                })),
+            },
+        }
+    }
+
+    // bigint 形如 0xFFn, 统一转换成十进制
+    pub(crate) fn from_bigint(bigint: &str) -> Literal {
+        let (radix, digits) = split_radix(bigint.strip_suffix('n').unwrap_or(bigint));
+
+        // 每个元素保存 9 位十进制数, 低位在前
+        const BASE: u64 = 1_000_000_000;
+        let mut limbs: Vec<u64> = vec![0];
+        for c in digits.chars() {
+            let mut carry = c.to_digit(radix).unwrap() as u64;
+            for limb in limbs.iter_mut() {
+                let value = *limb * radix as u64 + carry;
+                *limb = value % BASE;
+                carry = value / BASE;
+            }
+            if carry > 0 {
+                limbs.push(carry);
+            }
+        }
+
+        let mut decimal = limbs.pop().unwrap().to_string();
+        for limb in limbs.iter().rev() {
+            decimal.push_str(&format!("{:09}", limb));
+        }
+        Literal::BigInt(decimal)
+    }
+}
+
+fn split_radix(number: &str) -> (u32, &str) {
+    match number.as_bytes() {
+        [b'0', b'x' | b'X', _rest @ ..] => (16, &number[2..]),
+        [b'0', b'o' | b'O', _rest @ ..] => (8, &number[2..]),
+        [b'0', b'b' | b'B', _rest @ ..] => (2, &number[2..]),
+        _ => (10, number),
+    }
+}
+
 impl Visualizable for Literal {
     fn draw(&self, self_info: NodeInfo, graph: &mut AstGraph) {
         match self {
@@ -20,6 +86,9 @@ impl Visualizable for Literal {
             Literal::Integer(integer) => {
                 graph.put_node(self_info, &integer.to_string());
             }
+            Literal::BigInt(bigint) => {
+                graph.put_node(self_info, &format!("{}n", bigint));
+            }
             Literal::String(string) => {
                 let string = format!("\\\"{}\\\"", string);
                 graph.put_node(self_info, &string);
@@ -28,6 +97,11 @@ impl Visualizable for Literal {
                 let boolean_str = if *boolean { "true" } else { "false" };
                 graph.put_node(self_info, boolean_str);
             }
+            Literal::RegExp { pattern, flags } => {
+                // dot 的 label 中 \ 和 " 需要转义
+                let pattern = pattern.replace('\\', "\\\\").replace('"', "\\\"");
+                graph.put_node(self_info, &format!("/{}/{}", pattern, flags));
+            }
             Literal::Null => {
                 graph.put_node(self_info, "null");
             }
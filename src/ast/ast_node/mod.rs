@@ -7,8 +7,10 @@ pub mod decl;
 pub mod decorator;
 pub mod exp;
 pub mod identifier;
+pub mod jsx;
 pub mod literal;
 pub mod parameter;
+pub mod pattern;
 pub mod program;
 pub mod sig;
 pub mod source_element;
@@ -1,5 +1,6 @@
 use super::class::AccessModifier;
 use super::decorator::Decorators;
+use super::pattern::BindingTarget;
 use super::type_::*;
 use super::{exp::Exp, identifier::Identifier};
 use crate::ast::visulize::AstGraph;
@@ -9,7 +10,7 @@ use crate::lexer::token_kind::{KeyWordKind, TokenKind};
 #[derive(Visualizable, Default)]
 pub struct FormalParas {
     formal_paras: Vec<ASTNode<FormalPara>>,
-    last_para_arg: Option<ASTNode<Identifier>>,
+    last_para_arg: Option<ASTNode<RestPara>>,
 }
 
 impl FormalParas {
@@ -17,7 +18,7 @@ impl FormalParas {
         self.formal_paras.push(formal_para);
     }
 
-    pub(crate) fn set_last_para_arg(&mut self, last_para_arg: ASTNode<Identifier>) {
+    pub(crate) fn set_last_para_arg(&mut self, last_para_arg: ASTNode<RestPara>) {
         self.last_para_arg = Some(last_para_arg);
     }
 }
@@ -26,9 +27,10 @@ impl FormalParas {
 pub struct FormalPara {
     decorator: Option<TokenKind>,
     access_modifier: Option<ASTNode<AccessModifier>>,
-    identifier: ASTNode<Identifier>,
+    identifier: ASTNode<BindingTarget>,
     question_mark: Option<TokenKind>,
     type_annotation: Option<ASTNode<TypeAnnotation>>,
+    initializer: Option<ASTNode<Initializer>>,
 }
 
 impl FormalPara {
@@ -40,7 +42,7 @@ impl FormalPara {
         self.access_modifier = Some(access_modifier);
     }
 
-    pub(crate) fn set_identifier(&mut self, identifier: ASTNode<Identifier>) {
+    pub(crate) fn set_identifier(&mut self, identifier: ASTNode<BindingTarget>) {
         self.identifier = identifier;
     }
 
@@ -51,10 +53,72 @@ impl FormalPara {
     pub(crate) fn set_type_annotation(&mut self, type_annotation: ASTNode<TypeAnnotation>) {
         self.type_annotation = Some(type_annotation);
     }
+
+    pub(crate) fn set_initializer(&mut self, initializer: ASTNode<Initializer>) {
+        self.initializer = Some(initializer);
+    }
+}
+
+#[derive(Visualizable, Default)]
+pub struct TypeParas {
+    type_paras: Vec<ASTNode<TypePara>>,
+}
+
+impl TypeParas {
+    pub(crate) fn push_type_para(&mut self, type_para: ASTNode<TypePara>) {
+        self.type_paras.push(type_para);
+    }
+}
+
+// T extends Foo = Bar
+#[derive(Visualizable)]
+pub struct TypePara {
+    type_name: ASTNode<Identifier>,
+    constraint: Option<ASTNode<Constraint>>,
+    default_type: Option<ASTNode<DefaultType>>,
+}
+
+impl TypePara {
+    pub(crate) fn new(type_name: ASTNode<Identifier>) -> Self {
+        Self {
+            type_name,
+            constraint: None,
+            default_type: None,
+        }
+    }
+
+    pub(crate) fn set_constraint(&mut self, constraint: ASTNode<Constraint>) {
+        self.constraint = Some(constraint);
+    }
+
+    pub(crate) fn set_default_type(&mut self, default_type: ASTNode<DefaultType>) {
+        self.default_type = Some(default_type);
+    }
 }
 
+// extends Foo
 #[derive(Visualizable)]
-pub struct TypeParas {}
+pub struct Constraint {
+    type_: ASTNode<Type>,
+}
+
+impl Constraint {
+    pub(crate) fn new(type_: ASTNode<Type>) -> Self {
+        Self { type_ }
+    }
+}
+
+// = Bar
+#[derive(Visualizable)]
+pub struct DefaultType {
+    type_: ASTNode<Type>,
+}
+
+impl DefaultType {
+    pub(crate) fn new(type_: ASTNode<Type>) -> Self {
+        Self { type_ }
+    }
+}
 
 #[derive(Visualizable)]
 pub struct TypeAnnotation {
@@ -84,17 +148,31 @@ impl ParaList {
     }
 }
 
+// ...args: number[] 或者 ...[a, b]
 #[derive(Visualizable)]
 pub struct RestPara {
-    exp: Exp,
+    para_name: ASTNode<BindingTarget>,
     type_annotation: Option<ASTNode<TypeAnnotation>>,
 }
 
+impl RestPara {
+    pub(crate) fn new(para_name: ASTNode<BindingTarget>) -> Self {
+        Self {
+            para_name,
+            type_annotation: None,
+        }
+    }
+
+    pub(crate) fn set_type_annotation(&mut self, type_annotation: ASTNode<TypeAnnotation>) {
+        self.type_annotation = Some(type_annotation);
+    }
+}
+
 #[derive(Visualizable, Default)]
 pub struct Para {
     decorators: Option<ASTNode<Decorators>>,
     access_modifier: Option<ASTNode<AccessModifier>>,
-    para_name: ASTNode<Identifier>,
+    para_name: ASTNode<BindingTarget>,
     question_mark: Option<TokenKind>,
     type_annotation: Option<ASTNode<TypeAnnotation>>,
     initializer: Option<ASTNode<Initializer>>,
@@ -109,7 +187,7 @@ impl Para {
         self.access_modifier = Some(access_modifier);
     }
 
-    pub(crate) fn set_para_name(&mut self, para_name: ASTNode<Identifier>) {
+    pub(crate) fn set_para_name(&mut self, para_name: ASTNode<BindingTarget>) {
         self.para_name = para_name;
     }
 
@@ -0,0 +1,135 @@
+use crate::ast::visulize::AstGraph;
+use crate::ast::{ASTNode, NodeInfo, Visualizable};
+
+use super::exp::{Exp, PropertyName};
+use super::identifier::Identifier;
+use super::parameter::Initializer;
+
+// 解构: [a, , b = 1, ...rest] 或者 { a, b: c, d = 1, ...rest }
+#[derive(Visualizable)]
+pub enum BindingPattern {
+    ArrayPattern(ArrayPattern),
+    ObjectPattern(ObjectPattern),
+}
+
+// 被绑定的目标
+#[derive(Visualizable)]
+pub enum BindingTarget {
+    Identifier(Identifier),
+    BindingPattern(BindingPattern),
+    // 解构赋值中的目标可以是成员表达式, 例如 [this.a, b[0]] = arr
+    Exp(Box<Exp>),
+}
+
+impl Default for BindingTarget {
+    fn default() -> Self {
+        BindingTarget::Identifier(Identifier::default())
+    }
+}
+
+#[derive(Visualizable, Default)]
+pub struct ArrayPattern {
+    elements: Vec<ASTNode<ArrayPatternElement>>,
+    rest: Option<ASTNode<BindingTarget>>,
+}
+
+impl ArrayPattern {
+    pub(crate) fn push_element(&mut self, element: ASTNode<ArrayPatternElement>) {
+        self.elements.push(element);
+    }
+
+    pub(crate) fn set_rest(&mut self, rest: ASTNode<BindingTarget>) {
+        self.rest = Some(rest);
+    }
+}
+
+#[derive(Visualizable)]
+pub enum ArrayPatternElement {
+    // [, a] 中被跳过的位置
+    Elision(Elision),
+    BindingElement(BindingElement),
+}
+
+#[derive(Visualizable, Default)]
+pub struct Elision {}
+
+// target 或者 target = initializer
+#[derive(Visualizable, Default)]
+pub struct BindingElement {
+    target: ASTNode<BindingTarget>,
+    initializer: Option<ASTNode<Initializer>>,
+}
+
+impl BindingElement {
+    pub(crate) fn new(target: ASTNode<BindingTarget>) -> Self {
+        Self {
+            target,
+            initializer: None,
+        }
+    }
+
+    pub(crate) fn set_initializer(&mut self, initializer: ASTNode<Initializer>) {
+        self.initializer = Some(initializer);
+    }
+}
+
+#[derive(Visualizable, Default)]
+pub struct ObjectPattern {
+    properties: Vec<ASTNode<BindingProperty>>,
+    rest: Option<ASTNode<BindingTarget>>,
+}
+
+impl ObjectPattern {
+    pub(crate) fn push_property(&mut self, property: ASTNode<BindingProperty>) {
+        self.properties.push(property);
+    }
+
+    pub(crate) fn set_rest(&mut self, rest: ASTNode<BindingTarget>) {
+        self.rest = Some(rest);
+    }
+}
+
+#[derive(Visualizable)]
+pub enum BindingProperty {
+    // { a } 或者 { a = 1 }
+    SingleNameBinding(SingleNameBinding),
+    // { a: b }, { [key]: [c, d] = [] }
+    PropertyBinding(PropertyBinding),
+}
+
+#[derive(Visualizable)]
+pub struct SingleNameBinding {
+    identifier: ASTNode<Identifier>,
+    initializer: Option<ASTNode<Initializer>>,
+}
+
+impl SingleNameBinding {
+    pub(crate) fn new(identifier: ASTNode<Identifier>) -> Self {
+        Self {
+            identifier,
+            initializer: None,
+        }
+    }
+
+    pub(crate) fn set_initializer(&mut self, initializer: ASTNode<Initializer>) {
+        self.initializer = Some(initializer);
+    }
+}
+
+#[derive(Visualizable)]
+pub struct PropertyBinding {
+    property_name: ASTNode<PropertyName>,
+    element: ASTNode<BindingElement>,
+}
+
+impl PropertyBinding {
+    pub(crate) fn new(
+        property_name: ASTNode<PropertyName>,
+        element: ASTNode<BindingElement>,
+    ) -> Self {
+        Self {
+            property_name,
+            element,
+        }
+    }
+}
@@ -4,11 +4,13 @@ use visulize::Visualizable;
 use super::{
     block::{Block, CaseBlock},
     class::AccessModifier,
+    clause::{CatchClause, FinallyClause},
     decl::*,
     exp::{Exp, ExpSeq},
     identifier::Identifier,
     literal::Literal,
     parameter::{Initializer, TypeAnnotation},
+    pattern::BindingTarget,
     type_::TypeAlias,
     unknown::Unknown,
 };
@@ -39,7 +41,6 @@ pub enum Stat {
     ContinueStat(ContinueStat),
     BreakStat(BreakStat),
     ReturnStat(ReturnStat),
-    YieldStat(YieldStat),
     WithStat(WithStat),
 
     SwitchStat(SwitchStat),
@@ -232,6 +233,8 @@ pub enum IterStat {
     ForStat(ASTNode<ForStat>),
     ForVarStat(ASTNode<ForVarStat>),
     ForInStat(ASTNode<ForInStat>),
+    ForOfStat(ASTNode<ForOfStat>),
+    ForAwaitStat(ASTNode<ForAwaitStat>),
 }
 
 #[derive(Visualizable)]
@@ -306,15 +309,72 @@ impl ForVarStat {
         }
     }
 }
+// for (const key in obj), for (obj.key in source)
 #[derive(Visualizable)]
 pub struct ForInStat {
+    var_modifier: Option<ASTNode<VarModifier>>,
     var: ASTNode<Exp>,
     exp: ASTNode<Exp>,
     stat: ASTNode<Stat>,
 }
 impl ForInStat {
     pub(crate) fn new(var: ASTNode<Exp>, exp: ASTNode<Exp>, stat: ASTNode<Stat>) -> ForInStat {
-        Self { var, exp, stat }
+        Self {
+            var_modifier: None,
+            var,
+            exp,
+            stat,
+        }
+    }
+
+    pub(crate) fn set_var_modifier(&mut self, var_modifier: ASTNode<VarModifier>) {
+        self.var_modifier = Some(var_modifier);
+    }
+}
+
+// for (const [k, v] of map), for (obj.key of source)
+#[derive(Visualizable)]
+pub struct ForOfStat {
+    var_modifier: Option<ASTNode<VarModifier>>,
+    var: ASTNode<Exp>,
+    exp: ASTNode<Exp>,
+    stat: ASTNode<Stat>,
+}
+impl ForOfStat {
+    pub(crate) fn new(var: ASTNode<Exp>, exp: ASTNode<Exp>, stat: ASTNode<Stat>) -> ForOfStat {
+        Self {
+            var_modifier: None,
+            var,
+            exp,
+            stat,
+        }
+    }
+
+    pub(crate) fn set_var_modifier(&mut self, var_modifier: ASTNode<VarModifier>) {
+        self.var_modifier = Some(var_modifier);
+    }
+}
+
+// for await (const x of xs)
+#[derive(Visualizable)]
+pub struct ForAwaitStat {
+    var_modifier: Option<ASTNode<VarModifier>>,
+    var: ASTNode<Exp>,
+    exp: ASTNode<Exp>,
+    stat: ASTNode<Stat>,
+}
+impl ForAwaitStat {
+    pub(crate) fn new(var: ASTNode<Exp>, exp: ASTNode<Exp>, stat: ASTNode<Stat>) -> ForAwaitStat {
+        Self {
+            var_modifier: None,
+            var,
+            exp,
+            stat,
+        }
+    }
+
+    pub(crate) fn set_var_modifier(&mut self, var_modifier: ASTNode<VarModifier>) {
+        self.var_modifier = Some(var_modifier);
     }
 }
 
@@ -345,12 +405,12 @@ impl VarDeclList {
 
 #[derive(Visualizable)]
 pub struct VarDecl {
-    var_name: ASTNode<Identifier>,
+    var_name: ASTNode<BindingTarget>,
     type_annotation: Option<ASTNode<TypeAnnotation>>,
     initializer: Option<ASTNode<Exp>>,
 }
 impl VarDecl {
-    pub(crate) fn new(var_name: ASTNode<Identifier>) -> Self {
+    pub(crate) fn new(var_name: ASTNode<BindingTarget>) -> Self {
         Self {
             var_name,
             type_annotation: None,
@@ -397,16 +457,6 @@ impl ReturnStat {
     }
 }
 
-#[derive(Visualizable, Default)]
-pub struct YieldStat {
-    exp_seq: Option<ASTNode<ExpSeq>>,
-}
-impl YieldStat {
-    pub(crate) fn set_exp_seq(&mut self, exp_seq: ASTNode<ExpSeq>) {
-        self.exp_seq = Some(exp_seq);
-    }
-}
-
 #[derive(Visualizable, Default)]
 pub struct WithStat {
     exp_seq: ASTNode<ExpSeq>,
@@ -441,16 +491,34 @@ impl SwitchStat {
 }
 
 #[derive(Visualizable)]
-pub struct ThrowStat {}
+pub struct ThrowStat {
+    exp_seq: ASTNode<ExpSeq>,
+}
+impl ThrowStat {
+    pub(crate) fn new(exp_seq: ASTNode<ExpSeq>) -> Self {
+        Self { exp_seq }
+    }
+}
 
+// catch 和 finally 至少要有一个
 #[derive(Visualizable, Default)]
 pub struct TryStat {
     block: ASTNode<Block>,
+    catch_clause: Option<ASTNode<CatchClause>>,
+    finally_clause: Option<ASTNode<FinallyClause>>,
 }
 impl TryStat {
     pub(crate) fn set_block(&mut self, block: ASTNode<Block>) {
         self.block = block;
     }
+
+    pub(crate) fn set_catch_clause(&mut self, catch_clause: ASTNode<CatchClause>) {
+        self.catch_clause = Some(catch_clause);
+    }
+
+    pub(crate) fn set_finally_clause(&mut self, finally_clause: ASTNode<FinallyClause>) {
+        self.finally_clause = Some(finally_clause);
+    }
 }
 
 #[derive(Visualizable, Default)]
@@ -498,7 +566,5 @@ impl EnumMember {
     }
 }
 
-impl TryStat {}
-
 #[derive(Visualizable)]
 pub struct DebuggerStat {}
@@ -2,12 +2,15 @@ use super::decl::NamespaceName;
 use super::decl::ObjectType;
 use super::decl::TypeQuery;
 use super::identifier::Identifier;
+use super::literal::Literal;
 use super::parameter::ParaList;
+use super::parameter::TypeAnnotation;
 use super::parameter::TypeParas;
 use crate::ast::ASTNode;
 use crate::ast::AstGraph;
 use crate::ast::NodeInfo;
 use crate::ast::Visualizable;
+use crate::lexer::token_kind::TokenKind;
 
 #[derive(Visualizable)]
 pub struct TypeRef {
@@ -60,70 +63,285 @@ impl TypeGeneric {
     }
 }
 
+// 表达式中的类型实参, 例如 f<number>(x), new Map<string, number>()
 #[derive(Visualizable, Default)]
 pub struct TypeArgs {
     type_args: Vec<ASTNode<TypeArg>>,
 }
+impl TypeArgs {
+    pub(crate) fn push_type_arg(&mut self, type_arg: ASTNode<TypeArg>) {
+        self.type_args.push(type_arg);
+    }
+}
 
 #[derive(Visualizable)]
 pub struct TypeArg {
     type_arg: ASTNode<Type>,
 }
+impl TypeArg {
+    pub(crate) fn new(type_arg: ASTNode<Type>) -> Self {
+        Self { type_arg }
+    }
+}
 
 #[derive(Visualizable)]
+#[allow(clippy::enum_variant_names)]
 pub enum Type {
     PrimaryType(PrimaryType),
     FunctionType(FunctionType),
+    // A | B | C
+    UnionType(UnionType),
+    // A & B & C
+    IntersectionType(IntersectionType),
+    // T extends U ? X : Y
+    ConditionalType(ConditionalType),
 }
 
 #[derive(Visualizable)]
 pub enum PrimaryType {
     PredefinedType(PredefinedType),
     TypeRef(TypeRef),
-    ArrayPredefinedType(ArrayPredefinedType),
-    ArrayTypeRef(ArrayTypeRef),
+    // number[], T[][]
+    ArrayType(ArrayType),
     TupleType(TupleElementTypes),
     ObjectType(ObjectType),
     TypeQuery(TypeQuery),
+    // (A | B)
+    ParenthesizedType(ParenthesizedType),
+    // "a", 1, -1, true, null
+    LiteralType(Literal),
+    // keyof T
+    KeyofType(KeyofType),
+    // readonly T[], readonly [A, B]
+    ReadonlyType(ReadonlyType),
+    // T[K], T["name"]
+    IndexedAccessType(IndexedAccessType),
+    // { readonly [K in keyof T]?: T[K] }
+    MappedType(MappedType),
+    // `prefix-${string}`
+    TemplateLiteralType(TemplateLiteralType),
+    // infer R
+    InferType(InferType),
+}
+
+#[derive(Visualizable, Default)]
+pub struct UnionType {
+    types: Vec<ASTNode<Type>>,
+}
+impl UnionType {
+    pub(crate) fn push_type(&mut self, type_: ASTNode<Type>) {
+        self.types.push(type_);
+    }
+}
+
+#[derive(Visualizable, Default)]
+pub struct IntersectionType {
+    types: Vec<ASTNode<Type>>,
+}
+impl IntersectionType {
+    pub(crate) fn push_type(&mut self, type_: ASTNode<Type>) {
+        self.types.push(type_);
+    }
+}
+
+#[derive(Visualizable)]
+pub struct ConditionalType {
+    check_type: ASTNode<Type>,
+    extends_type: ASTNode<Type>,
+    true_type: ASTNode<Type>,
+    false_type: ASTNode<Type>,
+}
+impl ConditionalType {
+    pub(crate) fn new(
+        check_type: ASTNode<Type>,
+        extends_type: ASTNode<Type>,
+        true_type: ASTNode<Type>,
+        false_type: ASTNode<Type>,
+    ) -> Self {
+        Self {
+            check_type,
+            extends_type,
+            true_type,
+            false_type,
+        }
+    }
 }
 
 #[derive(Visualizable)]
 pub struct FunctionType {
+    type_paras: Option<ASTNode<TypeParas>>,
     para_list: Option<ASTNode<ParaList>>,
     type_: ASTNode<Type>,
 }
 impl FunctionType {
     pub(crate) fn new(para_list: Option<ASTNode<ParaList>>, type_: ASTNode<Type>) -> Self {
-        Self { para_list, type_ }
+        Self {
+            type_paras: None,
+            para_list,
+            type_,
+        }
+    }
+
+    pub(crate) fn set_type_paras(&mut self, type_paras: ASTNode<TypeParas>) {
+        self.type_paras = Some(type_paras);
+    }
+}
+
+#[derive(Visualizable)]
+pub struct ArrayType {
+    element_type: ASTNode<PrimaryType>,
+}
+impl ArrayType {
+    pub(crate) fn new(element_type: ASTNode<PrimaryType>) -> Self {
+        Self { element_type }
+    }
+}
+
+#[derive(Visualizable)]
+pub struct ParenthesizedType {
+    type_: ASTNode<Type>,
+}
+impl ParenthesizedType {
+    pub(crate) fn new(type_: ASTNode<Type>) -> Self {
+        Self { type_ }
+    }
+}
+
+#[derive(Visualizable)]
+pub struct KeyofType {
+    type_: ASTNode<PrimaryType>,
+}
+impl KeyofType {
+    pub(crate) fn new(type_: ASTNode<PrimaryType>) -> Self {
+        Self { type_ }
+    }
+}
+
+#[derive(Visualizable)]
+pub struct ReadonlyType {
+    type_: ASTNode<PrimaryType>,
+}
+impl ReadonlyType {
+    pub(crate) fn new(type_: ASTNode<PrimaryType>) -> Self {
+        Self { type_ }
     }
 }
 
 #[derive(Visualizable)]
-pub struct ArrayPredefinedType {
-    predefined_type: ASTNode<PredefinedType>,
+pub struct IndexedAccessType {
+    object_type: ASTNode<PrimaryType>,
+    index_type: ASTNode<Type>,
 }
-impl ArrayPredefinedType {
-    pub(crate) fn new(predefined_type: ASTNode<PredefinedType>) -> Self {
-        Self { predefined_type }
+impl IndexedAccessType {
+    pub(crate) fn new(object_type: ASTNode<PrimaryType>, index_type: ASTNode<Type>) -> Self {
+        Self {
+            object_type,
+            index_type,
+        }
     }
 }
-// impl Visualizable for ArrayPredefinedType {
-//     fn draw(&self, self_id: usize, graph: &mut AstGraph) {
-//         graph.put_node(self_id, "ArrayPredefinedType");
-//         self.predefined_type.draw(self_id, graph);
-//     }
-// }
 
+/*
+{ readonly [K in keyof T as NewName]?: T[K] }
+as 之后的类型用于重新映射属性名
+*/
 #[derive(Visualizable)]
-pub struct ArrayTypeRef {
-    array_type_ref: ASTNode<TypeRef>,
+pub struct MappedType {
+    readonly_modifier: Option<MappedModifier>,
+    type_para: ASTNode<Identifier>,
+    constraint: ASTNode<Type>,
+    name_type: Option<ASTNode<Type>>,
+    optional_modifier: Option<MappedModifier>,
+    type_annotation: Option<ASTNode<TypeAnnotation>>,
 }
-impl ArrayTypeRef {
-    pub(crate) fn new(type_ref: ASTNode<TypeRef>) -> Self {
+impl MappedType {
+    pub(crate) fn new(type_para: ASTNode<Identifier>, constraint: ASTNode<Type>) -> Self {
         Self {
-            array_type_ref: type_ref,
+            readonly_modifier: None,
+            type_para,
+            constraint,
+            name_type: None,
+            optional_modifier: None,
+            type_annotation: None,
         }
     }
+
+    pub(crate) fn set_readonly_modifier(&mut self, readonly_modifier: MappedModifier) {
+        self.readonly_modifier = Some(readonly_modifier);
+    }
+
+    pub(crate) fn set_name_type(&mut self, name_type: ASTNode<Type>) {
+        self.name_type = Some(name_type);
+    }
+
+    pub(crate) fn set_optional_modifier(&mut self, optional_modifier: MappedModifier) {
+        self.optional_modifier = Some(optional_modifier);
+    }
+
+    pub(crate) fn set_type_annotation(&mut self, type_annotation: ASTNode<TypeAnnotation>) {
+        self.type_annotation = Some(type_annotation);
+    }
+}
+
+// 映射类型的修饰符, + 表示添加, - 表示移除
+pub enum MappedModifier {
+    Readonly,
+    AddReadonly,
+    RemoveReadonly,
+    Optional,
+    AddOptional,
+    RemoveOptional,
+}
+
+impl Visualizable for MappedModifier {
+    fn draw(&self, self_info: NodeInfo, graph: &mut AstGraph) {
+        match self {
+            MappedModifier::Readonly => graph.put_node(self_info, "readonly"),
+            MappedModifier::AddReadonly => graph.put_node(self_info, "+readonly"),
+            MappedModifier::RemoveReadonly => graph.put_node(self_info, "-readonly"),
+            MappedModifier::Optional => graph.put_node(self_info, "?"),
+            MappedModifier::AddOptional => graph.put_node(self_info, "+?"),
+            MappedModifier::RemoveOptional => graph.put_node(self_info, "-?"),
+        }
+    }
+}
+
+// 模板字面量类型, 与 TemplateExp 一样, 只是 ${} 中是类型
+#[derive(Visualizable, Default)]
+pub struct TemplateLiteralType {
+    head: ASTNode<Literal>,
+    template_spans: Vec<ASTNode<TemplateTypeSpan>>,
+}
+impl TemplateLiteralType {
+    pub(crate) fn set_head(&mut self, head: ASTNode<Literal>) {
+        self.head = head;
+    }
+
+    pub(crate) fn push_template_span(&mut self, template_span: ASTNode<TemplateTypeSpan>) {
+        self.template_spans.push(template_span);
+    }
+}
+
+#[derive(Visualizable)]
+pub struct TemplateTypeSpan {
+    type_: ASTNode<Type>,
+    literal: ASTNode<Literal>,
+}
+impl TemplateTypeSpan {
+    pub(crate) fn new(type_: ASTNode<Type>, literal: ASTNode<Literal>) -> Self {
+        Self { type_, literal }
+    }
+}
+
+// 只能出现在条件类型的 extends 子句中
+#[derive(Visualizable)]
+pub struct InferType {
+    type_para: ASTNode<Identifier>,
+}
+impl InferType {
+    pub(crate) fn new(type_para: ASTNode<Identifier>) -> Self {
+        Self { type_para }
+    }
 }
 
 #[derive(Default)]
@@ -135,6 +353,11 @@ pub enum PredefinedType {
     String,
     Symbol,
     Void,
+    Unknown,
+    Never,
+    Undefined,
+    Object,
+    BigInt,
 }
 
 impl Visualizable for PredefinedType {
@@ -146,18 +369,48 @@ impl Visualizable for PredefinedType {
             PredefinedType::String => graph.put_node(self_info, "string"),
             PredefinedType::Symbol => graph.put_node(self_info, "symbol"),
             PredefinedType::Void => graph.put_node(self_info, "void"),
+            PredefinedType::Unknown => graph.put_node(self_info, "unknown"),
+            PredefinedType::Never => graph.put_node(self_info, "never"),
+            PredefinedType::Undefined => graph.put_node(self_info, "undefined"),
+            PredefinedType::Object => graph.put_node(self_info, "object"),
+            PredefinedType::BigInt => graph.put_node(self_info, "bigint"),
         }
     }
 }
 
-#[derive(Visualizable)]
+#[derive(Visualizable, Default)]
 pub struct TupleElementTypes {
     tuple_element_types: Vec<ASTNode<TupleElement>>,
 }
+impl TupleElementTypes {
+    pub(crate) fn push_tuple_element(&mut self, tuple_element: ASTNode<TupleElement>) {
+        self.tuple_element_types.push(tuple_element);
+    }
+}
 
+// 可选元素 [number, string?], 剩余元素 [string, ...number[]]
 #[derive(Visualizable)]
 pub struct TupleElement {
+    ellipsis: Option<TokenKind>,
     tuple_element: ASTNode<Type>,
+    question_mark: Option<TokenKind>,
+}
+impl TupleElement {
+    pub(crate) fn new(tuple_element: ASTNode<Type>) -> Self {
+        Self {
+            ellipsis: None,
+            tuple_element,
+            question_mark: None,
+        }
+    }
+
+    pub(crate) fn set_ellipsis(&mut self) {
+        self.ellipsis = Some(TokenKind::Ellipsis);
+    }
+
+    pub(crate) fn set_question_mark(&mut self) {
+        self.question_mark = Some(TokenKind::QuestionMark);
+    }
 }
 
 #[derive(Visualizable)]
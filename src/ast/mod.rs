@@ -99,24 +99,65 @@ impl<T: Visualizable> ASTNode<T> {
     }
 }
 
-#[derive(Debug, Default, Clone, Copy)]
+// 源码中的一个位置, offset 是字节偏移, line 和 column 从 1 开始
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Pos {
+    pub(crate) offset: usize,
+    pub(crate) line: usize,
+    pub(crate) column: usize,
+}
+
+impl Pos {
+    pub(crate) fn new(offset: usize, line: usize, column: usize) -> Self {
+        Self {
+            offset,
+            line,
+            column,
+        }
+    }
+
+    pub fn get_offset(&self) -> usize {
+        self.offset
+    }
+
+    pub fn get_line(&self) -> usize {
+        self.line
+    }
+
+    pub fn get_column(&self) -> usize {
+        self.column
+    }
+}
+
+impl std::fmt::Display for Pos {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+// [begin, end), 默认值 (line 为 0) 表示虚拟节点
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub struct Span {
-    pub(crate) begin: usize,
-    pub(crate) end: usize,
+    pub(crate) begin: Pos,
+    pub(crate) end: Pos,
 }
 
 impl Span {
-    pub(crate) fn new(begin: usize, end: usize) -> Self {
+    pub(crate) fn new(begin: Pos, end: Pos) -> Self {
         Self { begin, end }
     }
 
-    pub(crate) fn get_begin(&self) -> usize {
+    pub fn get_begin(&self) -> Pos {
         self.begin
     }
 
-    pub(crate) fn get_end(&self) -> usize {
+    pub fn get_end(&self) -> Pos {
         self.end
     }
+
+    pub(crate) fn is_virtual(&self) -> bool {
+        self.begin.line == 0
+    }
 }
 
 const EMPTY_ID: usize = 0;
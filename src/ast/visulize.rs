@@ -95,14 +95,14 @@ impl AstGraph {
     pub(crate) fn label(info: NodeInfo, desc: &str) -> String {
         let node = &AstGraph::node_name(info.id);
 
-        if info.span.begin == 0 {
+        if info.span.is_virtual() {
             assert_eq!(info.span.begin, info.span.end);
             format!("\t{}[label=\"{}\", color=red]\n", node, desc)
         } else {
             format!(
                 "\t{}[label=\"{}\"]\n",
                 node,
-                format!("{}\n[{}, {}]", desc, info.span.begin, info.span.end)
+                format!("{}\n[{}, {})", desc, info.span.begin, info.span.end)
             )
         }
     }
@@ -1,5 +1,5 @@
 use lazy_static::lazy_static;
-use std::sync::Mutex;
+use std::{path::Path, sync::Mutex};
 
 use crate::{
     ast::AST,
@@ -12,6 +12,14 @@ use crate::{
     utils::get_char_stream,
 };
 
+pub use crate::ast::{Pos, Span};
+pub use crate::lexer::{
+    incremental::TextEdit,
+    token::Token,
+    token_kind::{KeyWordKind, TokenKind},
+    trivia::{JsDoc, JsDocTag, Trivia, TriviaKind},
+};
+
 lazy_static! {
     pub static ref FILENAME: Mutex<String> = Mutex::new(String::new());
 }
@@ -21,6 +29,8 @@ pub type IResult<T> = Result<T, TSError>;
 pub struct Compiler {
     pub(crate) filename: String,
     show_ast: bool,
+    keep_trivia: bool,
+    dump_tokens: bool,
 }
 
 impl Compiler {
@@ -31,6 +41,8 @@ impl Compiler {
         Compiler {
             filename: filename.to_owned(),
             show_ast: false,
+            keep_trivia: false,
+            dump_tokens: false,
         }
     }
 
@@ -43,7 +55,24 @@ impl Compiler {
         self
     }
 
+    // 词法分析时保留空白和注释
+    pub fn set_keep_trivia(mut self) -> Self {
+        self.keep_trivia = true;
+        self
+    }
+
+    // 只打印 token 流, 不做语法分析
+    pub fn set_dump_tokens(mut self) -> Self {
+        self.dump_tokens = true;
+        self
+    }
+
     pub fn run(&self) -> IResult<()> {
+        if self.dump_tokens {
+            print!("{}", self.dump_tokens());
+            return Ok(());
+        }
+
         let ast = self.gen_ast()?;
         if self.show_ast {
             self.visualize(&ast);
@@ -58,18 +87,83 @@ impl Compiler {
         Ok(())
     }
 
+    // 只做词法分析, 返回 token 的数量, 用于 benches/lexer.rs
+    pub fn lex(src: &str) -> IResult<usize> {
+        let mut lexer = Lexer::new(src);
+        Ok(lexer.get_token_stream()?.len())
+    }
+
+    /*
+    每个 token 占一行, 出错的部分以 Error token 出现, 所有的词法错误附在最后
+    格式是稳定的, 可以直接用于 diff
+    */
+    pub fn dump_tokens(&self) -> String {
+        let char_stream = get_char_stream(&self.filename);
+        let (tokens, errors) = self.lexer(&char_stream).get_token_stream_with_errors();
+
+        let mut dump = String::new();
+        for token in tokens {
+            dump.push_str(&token.dump());
+            dump.push('\n');
+        }
+        for error in errors {
+            dump.push_str(&error.to_string());
+            dump.push('\n');
+        }
+        dump
+    }
+
+    // 按照当前的设置对文件做词法分析, 开启 keep_trivia 时空白和注释挂在 token 上
+    pub fn token_stream(&self) -> Vec<Token> {
+        let char_stream = get_char_stream(&self.filename);
+        self.lexer(&char_stream).get_token_stream_with_errors().0
+    }
+
+    // 按照当前的设置对整个源码做词法分析, 出错的部分以 Error token 出现
+    pub fn tokenize(&self, src: &str) -> Vec<Token> {
+        self.lexer(src).get_token_stream_with_errors().0
+    }
+
+    /*
+    供编辑器使用, src 是编辑之后的源码, tokens 是以同样的设置扫描编辑之前的源码得到的 token 流,
+    只重新扫描受编辑影响的部分
+    */
+    pub fn relex(&self, src: &str, tokens: Vec<Token>, edit: &TextEdit) -> Vec<Token> {
+        self.lexer(src).relex(tokens, edit).0
+    }
+
     // front part
+    // parser 不处理 Error token, 有词法错误时报告全部错误后停止, 不再进行语法分析
     fn gen_ast(&self) -> IResult<AST> {
         let char_stream = get_char_stream(&self.filename);
-        let mut lexer = Lexer::new(&char_stream);
-        let token_stream = lexer.get_token_stream()?;
+        let token_stream = self.lexer(&char_stream).get_token_stream()?;
         let mut parser = Parser::new(token_stream);
+        if self.is_jsx() {
+            parser = parser.set_jsx();
+        }
         parser.parse()
     }
 
+    // .tsx 和 .jsx 文件中可以使用 JSX
+    fn is_jsx(&self) -> bool {
+        self.filename.ends_with(".tsx") || self.filename.ends_with(".jsx")
+    }
+
+    fn lexer<'a>(&self, char_stream: &'a str) -> Lexer<'a> {
+        let mut lexer = Lexer::new(char_stream);
+        if self.keep_trivia {
+            lexer = lexer.set_keep_trivia();
+        }
+        if self.is_jsx() {
+            lexer = lexer.set_jsx();
+        }
+        lexer
+    }
+
     // plugin part
     fn visualize(&self, ast: &AST) {
-        ast.vis(&format!("{}.dot", self.filename.replace(".ts", "")));
+        let dot_file = Path::new(&self.filename).with_extension("dot");
+        ast.vis(&dot_file.to_string_lossy());
     }
 
     fn eval(&self, ast: &AST) -> IResult<()> {
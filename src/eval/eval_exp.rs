@@ -29,6 +29,15 @@ impl Eval {
                     Op::Dot => todo!(),
                     Op::Index => todo!(),
                     Op::Call => todo!(),
+                    Op::OptionalDot => todo!(),
+                    Op::OptionalIndex => todo!(),
+                    Op::OptionalCall => todo!(),
+                    Op::Power => todo!(),
+                    Op::PowerAssign => todo!(),
+                    Op::AndAssign => todo!(),
+                    Op::OrAssign => todo!(),
+                    Op::NullCoalesce => todo!(),
+                    Op::NullCoalesceAssign => todo!(),
                     Op::Multiply => left * right,
                     Op::Divide => left / right,
                     Op::Mod => left % right,
@@ -77,6 +86,7 @@ impl Eval {
             }) => Eval::eval_exp(exp.ctx_ref()),
             Exp::ArgsExp(_) => todo!(),
             Exp::FunctionExp(_) => todo!(),
+            Exp::GeneratorExp(_) => todo!(),
             Exp::ClassExp(_) => todo!(),
             Exp::ArrowFuncExp(_) => todo!(),
             Exp::NewExp(_) => todo!(),
@@ -84,14 +94,25 @@ impl Eval {
             Exp::Literal(literal) => Ok(match literal {
                 literal::Literal::Number(number) => EvalObj::Number(*number),
                 literal::Literal::Integer(integer) => EvalObj::Integer(*integer),
+                literal::Literal::BigInt(_) => todo!(),
                 literal::Literal::String(str) => todo!(),
                 literal::Literal::Boolean(bool) => todo!(),
+                literal::Literal::RegExp { .. } => todo!(),
                 literal::Literal::Null => EvalObj::NONE,
             }),
+            Exp::TemplateExp(_) => todo!(),
+            Exp::TaggedTemplateExp(_) => todo!(),
             Exp::This(_) => todo!(),
             Exp::Super(_) => todo!(),
             Exp::Identifier(identifier) => todo!(),
             Exp::ArrayExp(_) => todo!(),
+            Exp::ObjectLiteral(_) => todo!(),
+            Exp::BindingPattern(_) => todo!(),
+            Exp::SpreadElement(_) => todo!(),
+            Exp::YieldExp(_) => todo!(),
+            Exp::AwaitExp(_) => todo!(),
+            Exp::JsxElement(_) => todo!(),
+            Exp::JsxFragment(_) => todo!(),
         }
     }
 }
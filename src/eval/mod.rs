@@ -58,7 +58,6 @@ impl Eval {
             Stat::ContinueStat(_) => todo!(),
             Stat::BreakStat(_) => todo!(),
             Stat::ReturnStat(_) => todo!(),
-            Stat::YieldStat(_) => todo!(),
             Stat::WithStat(_) => todo!(),
             Stat::SwitchStat(_) => todo!(),
             Stat::ThrowStat(_) => todo!(),
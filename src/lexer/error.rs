@@ -1,17 +1,30 @@
 // define lexical-related errors
 #[derive(Debug)]
 pub struct LexerError {
-    err: String,
+    errs: Vec<String>,
 }
 
 impl LexerError {
     pub(crate) fn new(err: String) -> Self {
-        Self { err }
+        Self { errs: vec![err] }
+    }
+
+    // 把一次扫描中遇到的所有错误合并为一个
+    pub(crate) fn merge(errors: Vec<LexerError>) -> Self {
+        Self {
+            errs: errors.into_iter().flat_map(|e| e.errs).collect(),
+        }
     }
 }
 
 impl std::fmt::Display for LexerError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "LexerError: {}", self.err)
+        for (i, err) in self.errs.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "LexerError: {}", err)?;
+        }
+        Ok(())
     }
 }
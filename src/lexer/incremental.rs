@@ -0,0 +1,163 @@
+use std::ops::Range;
+
+use crate::ast::{Pos, Span};
+
+use super::{error::LexerError, token::Token, token_kind::TokenKind, Lexer};
+
+// 一次文本编辑: 把编辑前源码中 range (字节偏移) 的部分替换为 text
+// 偏移与 token 中的 offset 一致, 不包括文件开头的 BOM
+#[derive(Debug, Clone)]
+pub struct TextEdit {
+    range: Range<usize>,
+    text: String,
+}
+
+impl TextEdit {
+    pub fn new(range: Range<usize>, text: &str) -> Self {
+        Self {
+            range,
+            text: text.to_string(),
+        }
+    }
+
+    // 编辑之后源码长度的变化
+    fn delta(&self) -> isize {
+        self.text.len() as isize - self.range.len() as isize
+    }
+
+    // 新插入的文本在编辑之后的源码中的结束位置
+    fn new_end(&self) -> usize {
+        self.range.start + self.text.len()
+    }
+}
+
+impl<'a> Lexer<'a> {
+    /*
+    增量词法分析, self 必须由编辑之后的源码构建, tokens 是编辑之前的 token 流:
+        1. 从受编辑影响的第一个 token 的前一个 token 开始重新扫描,
+           模板的嵌套状态和上一个 token 的类型都可以从之前的 token 中恢复
+        2. 越过编辑区域之后, 一旦扫描出的 token 与平移之后的旧 token 相同, 并且两边都不在模板中,
+           之后的源码和扫描状态都完全相同, 剩下的旧 token 只需要平移位置
+    返回的错误只包含重新扫描的区域中的错误
+    开启 keep_trivia 时 trivia 也与完整扫描一致; JSX 的嵌套状态无法从 token 中恢复, 因此 JSX 模式下重新扫描整个源码
+    */
+    pub(crate) fn relex(
+        &mut self,
+        mut tokens: Vec<Token>,
+        edit: &TextEdit,
+    ) -> (Vec<Token>, Vec<LexerError>) {
+        if self.jsx {
+            return self.get_token_stream_with_errors();
+        }
+
+        // 与编辑区域相接或相交的第一个 token, 再往前退一个 token,
+        // 因为它的结尾可能与编辑的内容合并, 例如在 a 之后插入 b
+        let damaged = tokens
+            .iter()
+            .position(|token| token.peek_end().offset >= edit.range.start)
+            .unwrap_or(tokens.len());
+        let restart = damaged.saturating_sub(1);
+        let mut old_tokens = tokens.split_off(restart);
+
+        let mut template_braces = Vec::new();
+        for token in &tokens {
+            track_template(&mut template_braces, token);
+        }
+        if let Some(token) = tokens.last() {
+            // 同一行之后的 trivia 已经挂在这个 token 上, 不能再作为下一个 token 的 leading trivia
+            let end = token
+                .peek_trailing_trivia()
+                .last()
+                .map_or(token.peek_end(), |trivia| trivia.peek_span().end);
+            self.forward(end.offset);
+            self.prev_kind = Some(token.peek_kind());
+        }
+        self.template_braces = template_braces.clone();
+
+        // 只对编辑区域之后的旧位置有意义
+        let delta = edit.delta();
+        let shift = |pos: Pos| pos.offset.checked_add_signed(delta).unwrap();
+        let is_damaged = |token: &Token| token.peek_begin().offset < edit.range.end;
+
+        // old 之前的旧 token 都已经被新 token 覆盖了, old_braces 是扫描完它们之后的模板嵌套状态
+        let mut old = 0;
+        let mut old_braces = template_braces;
+        loop {
+            let token = self.next_token();
+            self.prev_kind = Some(token.peek_kind());
+            let is_eof = token.kind_is(TokenKind::EOF);
+            let begin = token.peek_begin().offset;
+
+            let mut synced = false;
+            if begin >= edit.new_end() && !is_eof {
+                while old < old_tokens.len()
+                    && (is_damaged(&old_tokens[old]) || shift(old_tokens[old].peek_begin()) < begin)
+                {
+                    track_template(&mut old_braces, &old_tokens[old]);
+                    old += 1;
+                }
+                if let Some(old_token) = old_tokens.get(old) {
+                    if shift(old_token.peek_begin()) == begin {
+                        track_template(&mut old_braces, old_token);
+                        old += 1;
+                        synced = old_token.peek_kind() == token.peek_kind()
+                            && old_token.peek_value() == token.peek_value()
+                            && shift(old_token.peek_end()) == token.peek_end().offset
+                            && old_braces.is_empty()
+                            && self.template_braces.is_empty();
+                    }
+                }
+            }
+
+            tokens.push(token);
+            if is_eof {
+                break;
+            }
+            if synced {
+                for mut old_token in old_tokens.drain(old..) {
+                    old_token.map_span(|span| {
+                        Span::new(
+                            self.line_index.pos(shift(span.begin)),
+                            self.line_index.pos(shift(span.end)),
+                        )
+                    });
+                    tokens.push(old_token);
+                }
+                break;
+            }
+        }
+
+        (tokens, std::mem::take(&mut self.errors))
+    }
+}
+
+// 与 Lexer 中的 template_braces 保持一致, 记录扫描完 token 之后的模板嵌套状态
+fn track_template(template_braces: &mut Vec<usize>, token: &Token) {
+    match token.peek_kind() {
+        TokenKind::TemplateHead => template_braces.push(0),
+        TokenKind::TemplateTail => {
+            template_braces.pop();
+        }
+        TokenKind::LeftBracket => {
+            if let Some(braces) = template_braces.last_mut() {
+                *braces += 1;
+            }
+        }
+        TokenKind::RightBracket => {
+            if let Some(braces) = template_braces.last_mut() {
+                *braces = braces.saturating_sub(1);
+            }
+        }
+        // 出错的模板: 由 } 开头说明闭合了 ${, 以 ${ 结尾说明又进入了一层
+        TokenKind::Error => {
+            let value = token.peek_value();
+            if value.starts_with('}') {
+                template_braces.pop();
+            }
+            if (value.starts_with('`') || value.starts_with('}')) && value.ends_with("${") {
+                template_braces.push(0);
+            }
+        }
+        _ => {}
+    }
+}
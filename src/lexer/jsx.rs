@@ -0,0 +1,191 @@
+use super::{is_identifier_part, token::Token, token_kind::TokenKind, Lexer, LexerResult};
+
+/*
+JSX 的扫描规则与普通的 ts 不同, 需要记录当前处于 JSX 的哪一部分:
+    Tag         <div ...> 或 </div> 之内, 属性名可以包含 -, 属性值字符串没有转义
+    Children    开始标签和闭合标签之间, 除了 < 和 { 之外都是文本
+    Expression  属性或子节点中的 {...}, 按普通的规则扫描
+*/
+pub(crate) enum JsxContext {
+    // closing 表示之后的 > 会结束这个标签, 即闭合标签 </div> 或者自闭合标签 <div />
+    Tag { closing: bool },
+    Children,
+    // braces 是内部尚未闭合的 { 的数量, templates 是进入时模板字符串的嵌套层数, 用于判断 } 属于谁
+    Expression { braces: usize, templates: usize },
+}
+
+impl<'a> Lexer<'a> {
+    pub(crate) fn set_jsx(mut self) -> Self {
+        self.jsx = true;
+        self
+    }
+
+    pub(super) fn is_jsx_children(&self) -> bool {
+        matches!(self.jsx_contexts.last(), Some(JsxContext::Children))
+    }
+
+    // 当前的 { } 属于 JSX 中的 {...}, 而不是其中嵌套的模板字符串
+    pub(super) fn is_jsx_expression(&self) -> bool {
+        match self.jsx_contexts.last() {
+            Some(JsxContext::Expression { templates, .. }) => {
+                *templates == self.template_braces.len()
+            }
+            _ => false,
+        }
+    }
+
+    pub(super) fn enter_jsx_expression(&mut self) -> Token {
+        self.jsx_contexts.push(JsxContext::Expression {
+            braces: 0,
+            templates: self.template_braces.len(),
+        });
+        self.make_token("{", TokenKind::LeftBracket)
+    }
+
+    // 记录尚未闭合的括号, JSX 标签之内的 { } 也是普通的括号
+    pub(super) fn track_bracket(&mut self, token: &Token) {
+        match token.peek_kind() {
+            kind @ (TokenKind::LeftParen
+            | TokenKind::LeftBrace
+            | TokenKind::LeftBracket
+            | TokenKind::TemplateHead) => self.brackets.push(kind),
+            TokenKind::RightParen
+            | TokenKind::RightBrace
+            | TokenKind::RightBracket
+            | TokenKind::TemplateTail => {
+                self.brackets.pop();
+            }
+            _ => {}
+        }
+    }
+
+    /*
+    花括号中紧跟在 { ; , 之后的位置是类型成员, 对象的属性或者语句的开头, 此时 < 不会是 JSX,
+    例如 interface I { <T>(x: T): T }, 但 JSX 中的 {...} 除外, 例如 <a>{<b />}</a>
+    */
+    fn is_member_start(&self) -> bool {
+        let in_braces = self.brackets.last() == Some(&TokenKind::LeftBracket)
+            && !matches!(
+                self.jsx_contexts.last(),
+                Some(JsxContext::Expression { braces: 0, .. })
+            );
+        in_braces
+            && matches!(
+                self.prev_kind,
+                Some(TokenKind::LeftBracket | TokenKind::SemiColon | TokenKind::Comma)
+            )
+    }
+
+    /*
+    只有在需要表达式的位置上, < 之后紧跟着标签名或者 > 时才是 JSX 的开始
+    <T,>() => {} 以及 <T extends U>() => {} 是泛型箭头函数
+    */
+    pub(super) fn is_jsx_start(&self) -> bool {
+        if !self.jsx || !self.is_regexp_allowed() || self.is_member_start() {
+            return false;
+        }
+
+        let rest = &self.src[self.offset() + 1..];
+        if rest.starts_with('>') {
+            return true;
+        }
+        match rest.chars().next() {
+            Some(c) if c == '_' || c == '$' || unicode_ident::is_xid_start(c) => {}
+            _ => return false,
+        }
+        let name_len = rest
+            .find(|c: char| !is_identifier_part(c))
+            .unwrap_or(rest.len());
+        let after = rest[name_len..].trim_start();
+        let is_generic = after.starts_with(',')
+            || after
+                .strip_prefix("extends")
+                .is_some_and(|after| after.starts_with(char::is_whitespace));
+        !is_generic
+    }
+
+    // 标签之内的 token
+    pub(super) fn scan_jsx_tag_token(&mut self) -> LexerResult<Token> {
+        let closing = matches!(
+            self.jsx_contexts.last(),
+            Some(JsxContext::Tag { closing: true })
+        );
+
+        match self.peek().unwrap() {
+            b'>' => {
+                self.jsx_contexts.pop();
+                if !closing {
+                    self.jsx_contexts.push(JsxContext::Children);
+                }
+                Ok(self.make_token(">", TokenKind::MoreThan))
+            }
+            // 自闭合标签 <div />
+            b'/' => {
+                if self.bytes.get(1) == Some(&b'>') {
+                    self.jsx_contexts.pop();
+                    self.jsx_contexts.push(JsxContext::Tag { closing: true });
+                }
+                Ok(self.make_token("/", TokenKind::Divide))
+            }
+            b'{' => Ok(self.enter_jsx_expression()),
+            // 属性值也可以是 JSX, 例如 icon=<img />
+            b'<' => {
+                self.jsx_contexts.push(JsxContext::Tag { closing: false });
+                Ok(self.make_token("<", TokenKind::LessThan))
+            }
+            b'=' => Ok(self.make_token("=", TokenKind::Assign)),
+            b'.' => Ok(self.make_token(".", TokenKind::Dot)),
+            b':' => Ok(self.make_token(":", TokenKind::Colon)),
+            quote @ (b'"' | b'\'') => {
+                // 属性值中没有转义, 可以跨行
+                let rest = &self.src[self.offset() + 1..];
+                match rest.find(quote as char) {
+                    Some(len) => {
+                        let value = rest[..len].to_string();
+                        self.forward(len + 2);
+                        Ok(Token::new(&value, TokenKind::String))
+                    }
+                    None => Err(self.report_error("Unterminated string literal")),
+                }
+            }
+            // 标签名和属性名, 例如 data-id, 关键字也当作普通的名字
+            _ if self.is_identifier_start() => {
+                let rest = &self.src[self.offset()..];
+                let len = rest
+                    .find(|c: char| !is_identifier_part(c) && c != '-')
+                    .unwrap_or(rest.len());
+                let name = rest[..len].to_string();
+                self.forward(len);
+                Ok(Token::new(&name, TokenKind::Identifier))
+            }
+            _ => Err(self.report_error(&format!(
+                "Unexpected character [{}] in JSX tag",
+                self.peek_char().unwrap()
+            ))),
+        }
+    }
+
+    // 子节点中的 token: 文本, {...}, 或者子标签和闭合标签的 <
+    pub(super) fn scan_jsx_children_token(&mut self) -> LexerResult<Token> {
+        match self.peek().unwrap() {
+            b'{' => Ok(self.enter_jsx_expression()),
+            b'<' => {
+                match self.bytes.get(1) == Some(&b'/') {
+                    true => {
+                        self.jsx_contexts.pop();
+                        self.jsx_contexts.push(JsxContext::Tag { closing: true });
+                    }
+                    false => self.jsx_contexts.push(JsxContext::Tag { closing: false }),
+                }
+                Ok(self.make_token("<", TokenKind::LessThan))
+            }
+            _ => {
+                let rest = &self.src[self.offset()..];
+                let len = rest.find(['<', '{']).unwrap_or(rest.len());
+                let text = rest[..len].to_string();
+                self.forward(len);
+                Ok(Token::new(&text, TokenKind::JsxText))
+            }
+        }
+    }
+}
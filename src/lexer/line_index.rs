@@ -0,0 +1,62 @@
+use std::cell::Cell;
+
+use crate::ast::Pos;
+
+/*
+每个文件只构建一次, 记录每一行起始处的字节偏移,
+之后通过二分查找把字节偏移映射回 (行, 列)
+
+    行号和列号都从 1 开始, 列号按字符 (而不是字节) 计数
+    \n, \r\n, \r, U+2028, U+2029 都视为换行
+
+Lexer 查询的偏移基本是递增的, 因此缓存上一次查询的结果, 同一行内只需要从上一次的位置开始数字符,
+否则很长的一行 (例如压缩过的代码) 会让每次查询都从行首开始数, 整体变成平方复杂度
+*/
+pub(crate) struct LineIndex<'a> {
+    src: &'a str,
+    line_starts: Vec<usize>,
+    // 上一次查询的 (行的下标, 偏移, 列号)
+    last: Cell<(usize, usize, usize)>,
+}
+
+impl<'a> LineIndex<'a> {
+    pub(crate) fn new(src: &'a str) -> Self {
+        let bytes = src.as_bytes();
+        let mut line_starts = vec![0];
+        for (i, &b) in bytes.iter().enumerate() {
+            match b {
+                b'\n' => line_starts.push(i + 1),
+                // \r\n 只算一次换行, 由 \n 处理
+                b'\r' if bytes.get(i + 1) != Some(&b'\n') => line_starts.push(i + 1),
+                // U+2028 和 U+2029 的 UTF-8 编码为 E2 80 A8 和 E2 80 A9
+                0xe2 if matches!(bytes.get(i + 1..i + 3), Some([0x80, 0xa8 | 0xa9])) => {
+                    line_starts.push(i + 3)
+                }
+                _ => {}
+            }
+        }
+        Self {
+            src,
+            line_starts,
+            last: Cell::new((0, 0, 1)),
+        }
+    }
+
+    pub(crate) fn pos(&self, offset: usize) -> Pos {
+        let offset = offset.min(self.src.len());
+        let line = match self.line_starts.binary_search(&offset) {
+            Ok(line) => line,
+            Err(next_line) => next_line - 1,
+        };
+        let (last_line, last_offset, last_column) = self.last.get();
+        let column = if line != last_line {
+            self.src[self.line_starts[line]..offset].chars().count() + 1
+        } else if offset >= last_offset {
+            last_column + self.src[last_offset..offset].chars().count()
+        } else {
+            last_column - self.src[offset..last_offset].chars().count()
+        };
+        self.last.set((line, offset, column));
+        Pos::new(offset, line + 1, column)
+    }
+}
@@ -1,15 +1,26 @@
 use crate::lexer::token_kind::{KeyWordKind, TokenKind};
 use lazy_static::lazy_static;
-use regex::Regex;
 
 use std::str::FromStr;
 use std::{collections::HashMap, str};
 
-use self::{error::LexerError, token::Token};
+use crate::ast::Span;
+
+use self::{
+    error::LexerError,
+    jsx::JsxContext,
+    line_index::LineIndex,
+    token::Token,
+    trivia::{JsDoc, Trivia, TriviaKind},
+};
 
 pub(crate) mod error;
+pub(crate) mod incremental;
+pub(crate) mod jsx;
+pub(crate) mod line_index;
 pub mod token;
 pub mod token_kind;
+pub mod trivia;
 
 lazy_static! {
     pub static ref KEYWORD: HashMap<&'static str, TokenKind> = {
@@ -48,6 +59,8 @@ lazy_static! {
         map.insert("from", TokenKind::KeyWord(KeyWordKind::From));
         map.insert("readonly", TokenKind::KeyWord(KeyWordKind::ReadOnly));
         map.insert("async", TokenKind::KeyWord(KeyWordKind::Async));
+        map.insert("await", TokenKind::KeyWord(KeyWordKind::Await));
+        map.insert("of", TokenKind::KeyWord(KeyWordKind::Of));
         map.insert("class", TokenKind::KeyWord(KeyWordKind::Class));
         map.insert("enum", TokenKind::KeyWord(KeyWordKind::Enum));
         map.insert("extends", TokenKind::KeyWord(KeyWordKind::Extends));
@@ -71,6 +84,13 @@ lazy_static! {
         map.insert("boolean", TokenKind::KeyWord(KeyWordKind::Boolean));
         map.insert("string", TokenKind::KeyWord(KeyWordKind::String));
         map.insert("symbol", TokenKind::KeyWord(KeyWordKind::Symbol));
+        map.insert("unknown", TokenKind::KeyWord(KeyWordKind::Unknown));
+        map.insert("never", TokenKind::KeyWord(KeyWordKind::Never));
+        map.insert("undefined", TokenKind::KeyWord(KeyWordKind::Undefined));
+        map.insert("object", TokenKind::KeyWord(KeyWordKind::Object));
+        map.insert("bigint", TokenKind::KeyWord(KeyWordKind::BigInt));
+        map.insert("keyof", TokenKind::KeyWord(KeyWordKind::Keyof));
+        map.insert("infer", TokenKind::KeyWord(KeyWordKind::Infer));
 
         map.insert("type", TokenKind::KeyWord(KeyWordKind::Type));
         map.insert("get", TokenKind::KeyWord(KeyWordKind::Get));
@@ -89,279 +109,563 @@ lazy_static! {
 
 type LexerResult<T> = Result<T, LexerError>;
 
-#[derive(Default)]
 pub(crate) struct Lexer<'a> {
+    src: &'a str,
     bytes: &'a [u8],
-    line: usize,
+    line_index: LineIndex<'a>,
+
+    // 每进入一层模板字符串的 `${`, 就压入一个计数器,
+    // 记录该层替换表达式内部尚未闭合的 { 的数量, 例如 `${ {a:1}.a }`
+    template_braces: Vec<usize>,
+
+    // 是否把空白和注释作为 trivia 挂在 token 上
+    keep_trivia: bool,
+
+    // 上一个有效 token 的类型, 用于判断 / 是除号还是正则表达式的开头
+    prev_kind: Option<TokenKind>,
+
+    // 扫描过程中遇到的所有词法错误
+    errors: Vec<LexerError>,
+
+    // 是否识别 JSX, 由文件扩展名 .tsx .jsx 决定
+    jsx: bool,
+    // JSX 的嵌套状态, 栈顶决定当前按哪种规则扫描
+    jsx_contexts: Vec<JsxContext>,
+    // JSX 模式下尚未闭合的 ( [ { 以及模板字符串的 ${, 用于判断 < 是否可能是 JSX 的开始
+    brackets: Vec<TokenKind>,
 }
 
 impl<'a> Lexer<'a> {
     pub(crate) fn new(chars: &'a str) -> Self {
+        // 文件开头的 BOM 不属于源码
+        let chars = chars.strip_prefix('\u{feff}').unwrap_or(chars);
         Self {
+            src: chars,
             bytes: chars.as_bytes(),
-            line: 1,
+            line_index: LineIndex::new(chars),
+            template_braces: Vec::new(),
+            keep_trivia: false,
+            prev_kind: None,
+            errors: Vec::new(),
+            jsx: false,
+            jsx_contexts: Vec::new(),
+            brackets: Vec::new(),
         }
     }
 
+    pub(crate) fn set_keep_trivia(mut self) -> Self {
+        self.keep_trivia = true;
+        self
+    }
+
+    /*
+    有任何词法错误时, 一次性返回全部错误
+    语法分析只使用这个接口, 因此错误恢复只对 Compiler::lex tokenize dump_tokens 等词法接口有意义
+    */
     pub(crate) fn get_token_stream(&mut self) -> LexerResult<Vec<Token>> {
+        let (tokens, errors) = self.get_token_stream_with_errors();
+        match errors.is_empty() {
+            true => Ok(tokens),
+            false => Err(LexerError::merge(errors)),
+        }
+    }
+
+    /*
+    遇到词法错误时不会停止, 而是把出错的部分作为一个 Error token, 然后从下一个可能的 token 边界继续扫描
+    因此总能得到一个以 EOF 结尾的 token 流
+    */
+    pub(crate) fn get_token_stream_with_errors(&mut self) -> (Vec<Token>, Vec<LexerError>) {
         let mut tokens = Vec::new();
 
         loop {
             let token = self.next_token();
-            match token {
-                Ok(token) => {
-                    let is_eof = token.kind_is(TokenKind::EOF);
-                    tokens.push(token);
-                    // once token has moved, we could not to access it again,
-                    // so we need to early figure out what the kind it is
-                    if is_eof {
+            self.prev_kind = Some(token.peek_kind());
+            let is_eof = token.kind_is(TokenKind::EOF);
+            tokens.push(token);
+            // once token has moved, we could not to access it again,
+            // so we need to early figure out what the kind it is
+            if is_eof {
+                break;
+            }
+        }
+
+        (tokens, std::mem::take(&mut self.errors))
+    }
+
+    fn report_error(&self, msg: &str) -> LexerError {
+        self.report_error_at(self.offset(), msg)
+    }
+
+    // 未闭合的字符串和模板报告在开头的引号处, 而不是行尾或者文件末尾
+    fn report_error_at(&self, offset: usize, msg: &str) -> LexerError {
+        let pos = self.line_index.pos(offset);
+        LexerError::new(format!(
+            "Line[{}], Column[{}]: {}",
+            pos.line, pos.column, msg
+        ))
+    }
+
+    pub(crate) fn next_token(&mut self) -> Token {
+        // JSX 子节点中的空白和注释都属于文本
+        let leading_trivia = match self.is_jsx_children() {
+            true => Vec::new(),
+            false => self.skip_unrelated(false),
+        };
+
+        let begin = self.offset();
+        let mut token = match self.scan_token() {
+            Ok(token) => token,
+            Err(e) => {
+                self.errors.push(e);
+                self.recover(begin);
+                Token::new(&self.src[begin..self.offset()], TokenKind::Error)
+            }
+        };
+        if self.jsx {
+            self.track_bracket(&token);
+        }
+
+        let end = self.offset();
+        token.set_span(Span::new(
+            self.line_index.pos(begin),
+            self.line_index.pos(end),
+        ));
+
+        if self.keep_trivia {
+            let trailing_trivia = match self.is_jsx_children() {
+                true => Vec::new(),
+                false => self.skip_unrelated(true),
+            };
+            token.set_trivia(leading_trivia, trailing_trivia);
+        }
+        token
+    }
+
+    /*
+    出错之后跳到下一个可能的 token 边界, 被跳过的文本就是 Error token 的值:
+        ' "     跳过字符串剩余的部分, 直到引号或者行尾
+        ` }     跳过模板剩余的部分, 直到 ` 或者 ${
+        /       正则表达式不能换行, 直到行尾
+        其他    跳过出错的字符以及紧跟的标识符字符, 例如 0o19 3in \u{zz}
+    */
+    fn recover(&mut self, begin: usize) {
+        match self.src.as_bytes()[begin] {
+            quote @ (b'\'' | b'"') => loop {
+                match self.bytes {
+                    [] | [b'\n' | b'\r', ..] => break,
+                    [b'\\', _rest @ ..] => {
+                        self.forward(1);
+                        self.skip_char();
+                    }
+                    [c, _rest @ ..] if *c == quote => {
+                        self.forward(1);
+                        break;
+                    }
+                    _ => self.skip_char(),
+                }
+            },
+            b'`' | b'}' => loop {
+                match self.bytes {
+                    [] => break,
+                    [b'`', _rest @ ..] => {
+                        self.forward(1);
                         break;
                     }
+                    [b'$', b'{', _rest @ ..] => {
+                        self.forward(2);
+                        self.template_braces.push(0);
+                        break;
+                    }
+                    [b'\\', _rest @ ..] => {
+                        self.forward(1);
+                        self.skip_char();
+                    }
+                    _ => self.skip_char(),
+                }
+            },
+            b'/' => {
+                while !self.is_line_terminator() && !self.bytes.is_empty() {
+                    self.skip_char();
+                }
+            }
+            first => {
+                self.skip_char();
+                // 数字中的 . 也一并跳过, 例如 1.5n
+                let is_number = first.is_ascii_digit() || first == b'.';
+                while let Some(c) = self.peek_char() {
+                    match is_identifier_part(c) || (is_number && c == '.') {
+                        true => self.skip_char(),
+                        false => break,
+                    }
                 }
-                Err(e) => return Err(e),
             }
         }
-
-        Ok(tokens)
     }
 
-    fn report_error(&self, msg: &str) -> LexerError {
-        LexerError::new(format!("Line[{}]: {}", self.line, msg))
+    fn skip_char(&mut self) {
+        if let Some(c) = self.peek_char() {
+            self.forward(c.len_utf8());
+        }
     }
 
-    pub(crate) fn next_token(&mut self) -> LexerResult<Token> {
-        self.skip_unrelated();
+    // 当前已经扫描过的字节数
+    fn offset(&self) -> usize {
+        self.src.len() - self.bytes.len()
+    }
 
+    fn scan_token(&mut self) -> LexerResult<Token> {
         if self.bytes.is_empty() {
-            return Ok(self.make_eof_token(self.line));
+            return Ok(self.make_eof_token());
+        }
+
+        match self.jsx_contexts.last() {
+            Some(JsxContext::Tag { .. }) => return self.scan_jsx_tag_token(),
+            Some(JsxContext::Children) => return self.scan_jsx_children_token(),
+            _ => {}
         }
 
         // as we had already test the len of bytes before,
         // so we can unwrap it directly
         match self.peek().unwrap() {
-            b'(' => Ok(self.make_token("(", self.line, TokenKind::LeftParen)),
-            b')' => Ok(self.make_token(")", self.line, TokenKind::RightParen)),
-            b'[' => Ok(self.make_token("[", self.line, TokenKind::LeftBrace)),
-            b']' => Ok(self.make_token("]", self.line, TokenKind::RightBrace)),
-            b'{' => Ok(self.make_token("{", self.line, TokenKind::LeftBracket)),
-            b'}' => Ok(self.make_token("}", self.line, TokenKind::RightBracket)),
-            b',' => Ok(self.make_token(",", self.line, TokenKind::Comma)),
-            b';' => Ok(self.make_token(";", self.line, TokenKind::SemiColon)),
-            b'?' => Ok(self.make_token("?", self.line, TokenKind::QuestionMark)),
-            b':' => Ok(self.make_token(":", self.line, TokenKind::Colon)),
-            b'~' => Ok(self.make_token("~", self.line, TokenKind::BitNot)),
-            b'@' => Ok(self.make_token("@", self.line, TokenKind::At)),
-
-            b'a'..=b'z' | b'A'..=b'Z' | b'_' => Ok(self.make_identifier_token()),
+            b'(' => Ok(self.make_token("(", TokenKind::LeftParen)),
+            b')' => Ok(self.make_token(")", TokenKind::RightParen)),
+            b'[' => Ok(self.make_token("[", TokenKind::LeftBrace)),
+            b']' => Ok(self.make_token("]", TokenKind::RightBrace)),
+            b'{' => {
+                if self.is_jsx_expression() {
+                    if let Some(JsxContext::Expression { braces, .. }) =
+                        self.jsx_contexts.last_mut()
+                    {
+                        *braces += 1;
+                    }
+                } else if let Some(braces) = self.template_braces.last_mut() {
+                    *braces += 1;
+                }
+                Ok(self.make_token("{", TokenKind::LeftBracket))
+            }
+            // } 闭合的是 JSX 中的 {...}
+            b'}' if self.is_jsx_expression() => {
+                match self.jsx_contexts.last_mut() {
+                    Some(JsxContext::Expression { braces: 0, .. }) => {
+                        self.jsx_contexts.pop();
+                    }
+                    Some(JsxContext::Expression { braces, .. }) => *braces -= 1,
+                    _ => unreachable!(),
+                }
+                Ok(self.make_token("}", TokenKind::RightBracket))
+            }
+            b'}' => match self.template_braces.last_mut() {
+                // } 闭合的是 `${`, 说明模板字符串还要继续
+                Some(0) => Ok(self.make_template_token()?),
+                Some(braces) => {
+                    *braces -= 1;
+                    Ok(self.make_token("}", TokenKind::RightBracket))
+                }
+                None => Ok(self.make_token("}", TokenKind::RightBracket)),
+            },
+            b',' => Ok(self.make_token(",", TokenKind::Comma)),
+            b';' => Ok(self.make_token(";", TokenKind::SemiColon)),
+            b':' => Ok(self.make_token(":", TokenKind::Colon)),
+            b'~' => Ok(self.make_token("~", TokenKind::BitNot)),
+            b'@' => Ok(self.make_token("@", TokenKind::At)),
+
+            b'a'..=b'z' | b'A'..=b'Z' | b'_' | b'$' => Ok(self.make_identifier_token()?),
+            b'#' => Ok(self.make_private_identifier_token()?),
             b'0'..=b'9' | b'.' if self.is_number() => Ok(self.make_number_token()?),
             b'"' | b'\'' => Ok(self.make_string_token()?),
+            b'`' => Ok(self.make_template_token()?),
 
             b'!' => match self.bytes {
                 [b'!', b'=', b'=', _res @ ..] => {
-                    Ok(self.make_token("!==", self.line, TokenKind::IdentityNotEquals))
+                    Ok(self.make_token("!==", TokenKind::IdentityNotEquals))
                 }
-                [b'!', b'=', _res @ ..] => {
-                    Ok(self.make_token("!=", self.line, TokenKind::NotEquals))
+                [b'!', b'=', _res @ ..] => Ok(self.make_token("!=", TokenKind::NotEquals)),
+                _ => Ok(self.make_token("!", TokenKind::Not)),
+            },
+
+            b'?' => match self.bytes {
+                // a?.5:b 是三元表达式
+                [b'?', b'.', b'0'..=b'9', _res @ ..] => {
+                    Ok(self.make_token("?", TokenKind::QuestionMark))
+                }
+                [b'?', b'.', _res @ ..] => Ok(self.make_token("?.", TokenKind::QuestionDot)),
+                [b'?', b'?', b'=', _res @ ..] => {
+                    Ok(self.make_token("??=", TokenKind::NullCoalesceAssign))
                 }
-                _ => Ok(self.make_token("!", self.line, TokenKind::Not)),
+                [b'?', b'?', _res @ ..] => Ok(self.make_token("??", TokenKind::NullCoalesce)),
+                _ => Ok(self.make_token("?", TokenKind::QuestionMark)),
             },
 
             b'*' => match self.bytes {
-                [b'*', b'=', _res @ ..] => {
-                    Ok(self.make_token("*=", self.line, TokenKind::MultiplyAssign))
-                }
-                _ => Ok(self.make_token("*", self.line, TokenKind::Multiply)),
+                [b'*', b'*', b'=', _res @ ..] => Ok(self.make_token("**=", TokenKind::PowerAssign)),
+                [b'*', b'*', _res @ ..] => Ok(self.make_token("**", TokenKind::Power)),
+                [b'*', b'=', _res @ ..] => Ok(self.make_token("*=", TokenKind::MultiplyAssign)),
+                _ => Ok(self.make_token("*", TokenKind::Multiply)),
             },
 
+            b'/' if self.is_regexp_allowed() => Ok(self.make_regexp_token()?),
             b'/' => match self.bytes {
-                [b'/', b'=', _res @ ..] => {
-                    Ok(self.make_token("/=", self.line, TokenKind::DivideAssign))
-                }
-                _ => Ok(self.make_token("/", self.line, TokenKind::Divide)),
+                [b'/', b'=', _res @ ..] => Ok(self.make_token("/=", TokenKind::DivideAssign)),
+                _ => Ok(self.make_token("/", TokenKind::Divide)),
             },
 
             b'%' => match self.bytes {
-                [b'%', b'=', _res @ ..] => {
-                    Ok(self.make_token("%=", self.line, TokenKind::ModulusAssign))
-                }
-                _ => Ok(self.make_token("%", self.line, TokenKind::Modulus)),
+                [b'%', b'=', _res @ ..] => Ok(self.make_token("%=", TokenKind::ModulusAssign)),
+                _ => Ok(self.make_token("%", TokenKind::Modulus)),
             },
 
             b'.' => match self.bytes {
-                [b'.', b'.', b'.', _res @ ..] => {
-                    Ok(self.make_token("...", self.line, TokenKind::Ellipsis))
-                }
-                _ => Ok(self.make_token(".", self.line, TokenKind::Dot)),
+                [b'.', b'.', b'.', _res @ ..] => Ok(self.make_token("...", TokenKind::Ellipsis)),
+                _ => Ok(self.make_token(".", TokenKind::Dot)),
             },
 
             b'+' => match self.bytes {
-                [b'+', b'+', _res @ ..] => {
-                    Ok(self.make_token("++", self.line, TokenKind::PlusPlus))
-                }
-                [b'+', b'=', _res @ ..] => {
-                    Ok(self.make_token("+=", self.line, TokenKind::PlusAssign))
-                }
-                _ => Ok(self.make_token("+", self.line, TokenKind::Plus)),
+                [b'+', b'+', _res @ ..] => Ok(self.make_token("++", TokenKind::PlusPlus)),
+                [b'+', b'=', _res @ ..] => Ok(self.make_token("+=", TokenKind::PlusAssign)),
+                _ => Ok(self.make_token("+", TokenKind::Plus)),
             },
 
             b'-' => match self.bytes {
-                [b'-', b'-', _res @ ..] => {
-                    Ok(self.make_token("--", self.line, TokenKind::MinusMinus))
-                }
-                [b'-', b'=', _res @ ..] => {
-                    Ok(self.make_token("-=", self.line, TokenKind::MinusAssign))
-                }
-                _ => Ok(self.make_token("-", self.line, TokenKind::Minus)),
+                [b'-', b'-', _res @ ..] => Ok(self.make_token("--", TokenKind::MinusMinus)),
+                [b'-', b'=', _res @ ..] => Ok(self.make_token("-=", TokenKind::MinusAssign)),
+                _ => Ok(self.make_token("-", TokenKind::Minus)),
             },
 
             b'>' => match self.bytes {
                 [b'>', b'>', b'>', b'=', _res @ ..] => {
-                    Ok(self.make_token(">>>=", self.line, TokenKind::RightShiftLogicalAssign))
+                    Ok(self.make_token(">>>=", TokenKind::RightShiftLogicalAssign))
                 }
                 [b'>', b'>', b'=', _res @ ..] => {
-                    Ok(self.make_token(">>=", self.line, TokenKind::RightShiftArithmeticAssign))
+                    Ok(self.make_token(">>=", TokenKind::RightShiftArithmeticAssign))
                 }
                 [b'>', b'>', b'>', _res @ ..] => {
-                    Ok(self.make_token(">>>", self.line, TokenKind::RightShiftLogical))
+                    Ok(self.make_token(">>>", TokenKind::RightShiftLogical))
                 }
                 [b'>', b'>', _res @ ..] => {
-                    Ok(self.make_token(">>", self.line, TokenKind::RightShiftArithmetic))
+                    Ok(self.make_token(">>", TokenKind::RightShiftArithmetic))
                 }
-                [b'>', b'=', _res @ ..] => {
-                    Ok(self.make_token(">=", self.line, TokenKind::GreaterThanEquals))
-                }
-                _ => Ok(self.make_token(">", self.line, TokenKind::MoreThan)),
+                [b'>', b'=', _res @ ..] => Ok(self.make_token(">=", TokenKind::GreaterThanEquals)),
+                _ => Ok(self.make_token(">", TokenKind::MoreThan)),
             },
 
+            b'<' if self.is_jsx_start() => {
+                self.jsx_contexts.push(JsxContext::Tag { closing: false });
+                Ok(self.make_token("<", TokenKind::LessThan))
+            }
             b'<' => match self.bytes {
                 [b'<', b'<', b'=', _res @ ..] => {
-                    Ok(self.make_token("<<=", self.line, TokenKind::LeftShiftArithmeticAssign))
+                    Ok(self.make_token("<<=", TokenKind::LeftShiftArithmeticAssign))
                 }
                 [b'<', b'<', _res @ ..] => {
-                    Ok(self.make_token("<<", self.line, TokenKind::LeftShiftArithmetic))
+                    Ok(self.make_token("<<", TokenKind::LeftShiftArithmetic))
                 }
-                [b'<', b'=', _res @ ..] => {
-                    Ok(self.make_token("<=", self.line, TokenKind::LessThanEquals))
-                }
-                _ => Ok(self.make_token("<", self.line, TokenKind::LessThan)),
+                [b'<', b'=', _res @ ..] => Ok(self.make_token("<=", TokenKind::LessThanEquals)),
+                _ => Ok(self.make_token("<", TokenKind::LessThan)),
             },
 
             b'=' => match self.bytes {
                 [b'=', b'=', b'=', _res @ ..] => {
-                    Ok(self.make_token("===", self.line, TokenKind::IdentityEquals))
+                    Ok(self.make_token("===", TokenKind::IdentityEquals))
                 }
-                [b'=', b'=', _res @ ..] => Ok(self.make_token("==", self.line, TokenKind::Equals)),
-                [b'=', b'>', _res @ ..] => Ok(self.make_token("=>", self.line, TokenKind::Arrow)),
-                _ => Ok(self.make_token("=", self.line, TokenKind::Assign)),
+                [b'=', b'=', _res @ ..] => Ok(self.make_token("==", TokenKind::Equals)),
+                [b'=', b'>', _res @ ..] => Ok(self.make_token("=>", TokenKind::Arrow)),
+                _ => Ok(self.make_token("=", TokenKind::Assign)),
             },
             b'&' => match self.bytes {
-                [b'&', b'&', _res @ ..] => Ok(self.make_token("&&", self.line, TokenKind::And)),
-                [b'&', b'=', _res @ ..] => {
-                    Ok(self.make_token("&=", self.line, TokenKind::BitAndAssign))
-                }
-                _ => Ok(self.make_token("&", self.line, TokenKind::BitAnd)),
+                [b'&', b'&', b'=', _res @ ..] => Ok(self.make_token("&&=", TokenKind::AndAssign)),
+                [b'&', b'&', _res @ ..] => Ok(self.make_token("&&", TokenKind::And)),
+                [b'&', b'=', _res @ ..] => Ok(self.make_token("&=", TokenKind::BitAndAssign)),
+                _ => Ok(self.make_token("&", TokenKind::BitAnd)),
             },
 
             b'^' => match self.bytes {
-                [b'^', b'=', _res @ ..] => {
-                    Ok(self.make_token("^=", self.line, TokenKind::BitXorAssign))
-                }
-                _ => Ok(self.make_token("^", self.line, TokenKind::BitXOr)),
+                [b'^', b'=', _res @ ..] => Ok(self.make_token("^=", TokenKind::BitXorAssign)),
+                _ => Ok(self.make_token("^", TokenKind::BitXOr)),
             },
 
             b'|' => match self.bytes {
-                [b'|', b'=', _res @ ..] => {
-                    Ok(self.make_token("|=", self.line, TokenKind::BitOrAssign))
-                }
-                [b'|', b'|', _res @ ..] => Ok(self.make_token("||", self.line, TokenKind::Or)),
-                _ => Ok(self.make_token("|", self.line, TokenKind::BitOr)),
+                [b'|', b'=', _res @ ..] => Ok(self.make_token("|=", TokenKind::BitOrAssign)),
+                [b'|', b'|', b'=', _res @ ..] => Ok(self.make_token("||=", TokenKind::OrAssign)),
+                [b'|', b'|', _res @ ..] => Ok(self.make_token("||", TokenKind::Or)),
+                _ => Ok(self.make_token("|", TokenKind::BitOr)),
             },
 
-            _ => {
-                let src = String::from_utf8_lossy(self.bytes);
-                Err(self.report_error(&format!(
-                    "Unexpected character [{}] [Our compiler just supports ASCII]",
-                    src.chars().next().unwrap(),
-                )))
-            }
+            // 非 ASCII 字符或者 \u 转义开头的标识符
+            _ if self.is_identifier_start() => Ok(self.make_identifier_token()?),
+
+            _ => Err(self.report_error(&format!(
+                "Unexpected character [{}]",
+                self.peek_char().unwrap()
+            ))),
         }
     }
 
     fn is_ws(&self) -> bool {
-        if self.bytes.is_empty() {
-            return false;
+        match self.peek_char() {
+            Some(c) => is_whitespace(c),
+            None => false,
+        }
+    }
+
+    fn is_identifier_start(&self) -> bool {
+        match self.peek_char() {
+            Some('$' | '_') => true,
+            Some('\\') => matches!(self.bytes, [b'\\', b'u', _rest @ ..]),
+            Some(c) => unicode_ident::is_xid_start(c),
+            None => false,
         }
-        matches!(self.bytes[0], b' ' | b'\r' | b'\n' | b'\t')
     }
 
     fn is_comment(&self) -> bool {
-        matches!(self.bytes, [b'/', b'/' | b'*', _res @ ..])
+        match self.bytes {
+            [b'/', b'/' | b'*', _res @ ..] => true,
+            [b'#', b'!', _res @ ..] => self.offset() == 0,
+            _ => false,
+        }
     }
 
     fn is_number(&self) -> bool {
         matches!(self.bytes, [b'0'..=b'9', _res @ ..] | [b'.', b'0'..=b'9', _res @ ..])
     }
 
-    fn skip_unrelated(&mut self) {
+    // 上一个 token 能够结束一个表达式时, / 是除号, 否则是正则表达式
+    fn is_regexp_allowed(&self) -> bool {
+        match self.prev_kind {
+            None => true,
+            Some(
+                TokenKind::Identifier
+                | TokenKind::PrivateIdentifier
+                | TokenKind::Number
+                | TokenKind::BigInt
+                | TokenKind::String
+                | TokenKind::RegExp
+                | TokenKind::NoSubstitutionTemplate
+                | TokenKind::TemplateTail
+                | TokenKind::RightParen
+                | TokenKind::RightBrace
+                | TokenKind::RightBracket
+                | TokenKind::PlusPlus
+                | TokenKind::MinusMinus,
+            ) => false,
+            // 只有这些关键字之后可以跟表达式, 其余关键字都可能被当作标识符使用
+            Some(TokenKind::KeyWord(keyword)) => matches!(
+                keyword,
+                KeyWordKind::Return
+                    | KeyWordKind::Typeof
+                    | KeyWordKind::Instanceof
+                    | KeyWordKind::In
+                    | KeyWordKind::New
+                    | KeyWordKind::Delete
+                    | KeyWordKind::Void
+                    | KeyWordKind::Throw
+                    | KeyWordKind::Case
+                    | KeyWordKind::Do
+                    | KeyWordKind::Else
+                    | KeyWordKind::Yield
+                    | KeyWordKind::Await
+            ),
+            Some(_) => true,
+        }
+    }
+
+    /*
+    跳过空白和注释, 开启 keep_trivia 时会把它们收集起来
+    stop_at_newline 为 true 时遇到换行就停止, 用于收集 trailing trivia
+    */
+    fn skip_unrelated(&mut self, stop_at_newline: bool) -> Vec<Trivia> {
+        let mut trivias = Vec::new();
         loop {
-            match (self.is_ws(), self.is_comment()) {
+            let begin = self.offset();
+            let kind = match (self.is_ws(), self.is_comment()) {
                 (false, false) => break,
+                (true, false) if stop_at_newline && self.is_line_terminator() => break,
                 (true, false) => self.skip_ws(),
                 (false, true) => self.skip_comment(),
                 (true, true) => unreachable!(),
+            };
+
+            if self.keep_trivia {
+                let end = self.offset();
+                let span = Span::new(self.line_index.pos(begin), self.line_index.pos(end));
+                trivias.push(Trivia::new(kind, &self.src[begin..end], span));
             }
         }
+        trivias
+    }
+
+    fn is_line_terminator(&self) -> bool {
+        self.peek_char().is_some_and(is_line_terminator)
     }
 
-    fn skip_ws(&mut self) {
+    // 一次只跳过一个换行, 或者一段连续的非换行空白
+    fn skip_ws(&mut self) -> TriviaKind {
+        match self.bytes {
+            [b'\r', b'\n', _rest @ ..] => {
+                self.forward(2);
+                return TriviaKind::NewLine;
+            }
+            [b'\n' | b'\r', _rest @ ..] => {
+                self.forward(1);
+                return TriviaKind::NewLine;
+            }
+            _ if self.is_line_terminator() => {
+                self.forward(3);
+                return TriviaKind::NewLine;
+            }
+            _ => {}
+        }
+
         loop {
             match self.bytes.first() {
-                Some(b'\n') | Some(b'\r') => {
-                    self.inc_line();
-                    self.forward(1);
-                }
                 Some(b' ') | Some(b'\t') => {
                     self.forward(1);
                 }
-                _ => break,
+                Some(b'\n') | Some(b'\r') => break,
+                _ => match self.peek_char() {
+                    Some(c) if is_whitespace(c) && !is_line_terminator(c) => {
+                        self.forward(c.len_utf8())
+                    }
+                    _ => break,
+                },
             }
         }
+        TriviaKind::Whitespace
     }
 
-    fn skip_comment(&mut self) {
-        lazy_static! {
-            static ref SINGLE_LINE_COMMENTS_RE: Regex = Regex::new(r"(^//.*)").unwrap();
-            static ref MULTI_LINE_COMMENTS_RE: Regex = Regex::new(r"(^/[*][\s\S]*?[*]/)").unwrap();
+    /*
+    一次只跳过一个注释:
+        // ...      直到换行之前
+        #!...       只能出现在文件开头, 同样直到换行之前
+        /* ... */   不能嵌套, 未闭合时报错, 并视为一直延续到文件末尾
+    */
+    fn skip_comment(&mut self) -> TriviaKind {
+        if let [b'/', b'/', _rest @ ..] | [b'#', b'!', _rest @ ..] = self.bytes {
+            let kind = match self.bytes[0] {
+                b'#' => TriviaKind::Hashbang,
+                _ => TriviaKind::SingleLineComment,
+            };
+            let len = self.src[self.offset()..]
+                .find(is_line_terminator)
+                .unwrap_or(self.bytes.len());
+            self.forward(len);
+            return kind;
         }
 
-        loop {
-            let src = str::from_utf8(self.bytes).unwrap_or("");
-            let single_line_comments = SINGLE_LINE_COMMENTS_RE.captures(src);
-            let multi_line_comments = MULTI_LINE_COMMENTS_RE.captures(src);
-            match (single_line_comments, multi_line_comments) {
-                (None, None) => break,
-                (Some(single), None) => {
-                    self.forward(single.get(1).unwrap().end());
-                }
-                (None, Some(multi)) => {
-                    let len = multi.get(1).unwrap().end();
-                    let mut slice = &self.bytes[..len];
-                    loop {
-                        match slice {
-                            [b'\n' | b'\r', rest @ ..] => {
-                                slice = rest;
-                                self.line += 1
-                            }
-                            [_, rest @ ..] => {
-                                slice = rest;
-                            }
-                            _ => break,
-                        }
-                    }
-                    self.forward(len);
-                }
-                (Some(_), Some(_)) => unreachable!(),
+        let len = match self.bytes[2..].windows(2).position(|w| w == b"*/") {
+            Some(pos) => pos + 4,
+            None => {
+                let e = self.report_error("'*/' expected");
+                self.errors.push(e);
+                self.bytes.len()
             }
+        };
+        let comment = &self.bytes[..len];
+        self.forward(len);
+
+        // /**/ 不是 JSDoc, 并且只有需要保留 trivia 时才解析 JSDoc
+        match comment.starts_with(b"/**") && comment != b"/**/" && self.keep_trivia {
+            true => TriviaKind::JsDoc(JsDoc::parse(str::from_utf8(comment).unwrap())),
+            false => TriviaKind::MultiLineComment,
         }
     }
 
@@ -372,127 +676,214 @@ impl<'a> Lexer<'a> {
         }
     }
 
-    fn make_token(&mut self, value: &'static str, line: usize, kind: TokenKind) -> Token {
+    fn peek_char(&self) -> Option<char> {
+        self.char_at(0)
+    }
+
+    // 源码总是合法的 UTF-8, offset 必须位于字符边界
+    fn char_at(&self, offset: usize) -> Option<char> {
+        let len = match self.bytes.get(offset)? {
+            0x00..=0x7f => 1,
+            0xc0..=0xdf => 2,
+            0xe0..=0xef => 3,
+            _ => 4,
+        };
+        let end = (offset + len).min(self.bytes.len());
+        str::from_utf8(&self.bytes[offset..end])
+            .ok()?
+            .chars()
+            .next()
+    }
+
+    fn make_token(&mut self, value: &'static str, kind: TokenKind) -> Token {
         self.forward(value.len());
-        Token::new(value, line, kind)
+        Token::new(value, kind)
     }
 
-    fn make_eof_token(&self, line: usize) -> Token {
-        Token::new("$", line, TokenKind::EOF)
+    fn make_eof_token(&self) -> Token {
+        Token::new("$", TokenKind::EOF)
     }
 
+    /*
+    数字字面量:
+        0x1F 0o17 0b11      其他进制的整数
+        123 1.5 .5 1e-3     十进制数
+        1_000_000           数字分隔符只能出现在两个数字之间
+        10n 0xFFn           BigInt, 只能是整数
+    token 的值是去掉分隔符之后的原始文本
+    */
     fn make_number_token(&mut self) -> LexerResult<Token> {
-        lazy_static! {
-            static ref HEX_RE: Regex = Regex::new(r"(^0[xX][0-9a-fA-F]+)").unwrap();
-            static ref OCT_RE: Regex = Regex::new(r"(^0[oO][0-7]+)").unwrap();
-            static ref OCT_RE2: Regex = Regex::new(r"(^0[0-7]+)").unwrap();
-            static ref BIN_RE: Regex = Regex::new(r"(^0[bB][01]+)").unwrap();
-        }
-
-        let src = str::from_utf8(self.bytes);
-        if src.is_err() {
-            return Err(self.report_error("UFT8 ERROR"));
-        }
+        let (radix, mut len) = match self.bytes {
+            [b'0', b'x' | b'X', _rest @ ..] => (16, 2),
+            [b'0', b'o' | b'O', _rest @ ..] => (8, 2),
+            [b'0', b'b' | b'B', _rest @ ..] => (2, 2),
+            _ => (10, 0),
+        };
 
-        let src = src.unwrap();
-        match self.bytes {
-            [b'0', b'x' | b'X', _res @ ..] => match HEX_RE.captures(src) {
-                Some(hex) => {
-                    let hex = hex.get(1).unwrap().as_str();
-                    self.forward(hex.len());
-                    Ok(Token::new(hex, self.line, TokenKind::Number))
-                }
-                None => Err(self.report_error("unknown number character")),
-            },
-            [b'0', b'o' | b'O', _res @ ..] => match OCT_RE.captures(src) {
-                Some(oct) => {
-                    let oct = oct.get(1).unwrap().as_str();
-                    self.forward(oct.len());
-                    Ok(Token::new(oct, self.line, TokenKind::Number))
+        let mut is_integer = true;
+        if radix != 10 {
+            let digits = self.scan_digits(len, radix)?;
+            if digits == 0 {
+                return Err(self.report_error(match radix {
+                    16 => "Hexadecimal digit expected",
+                    8 => "Octal digit expected",
+                    _ => "Binary digit expected",
+                }));
+            }
+            len += digits;
+        } else {
+            match self.bytes {
+                [b'0', b'0'..=b'7', _rest @ ..] => {
+                    let digits = self.scan_digits(1, 10)?;
+                    let octal = str::from_utf8(&self.bytes[1..1 + digits]).unwrap();
+                    return Err(self.report_error(&format!(
+                        "Octal literals are not allowed, use the syntax '0o{}'",
+                        octal
+                    )));
                 }
-                None => Err(self.report_error("unknown number character")),
-            },
-            [b'0', b'0'..=b'7', _res @ ..] => match OCT_RE2.captures(src) {
-                Some(oct2) => {
-                    let oct2 = oct2.get(1).unwrap().as_str();
-                    self.forward(oct2.len());
-                    Ok(Token::new(oct2, self.line, TokenKind::Number))
+                [b'0', b'8' | b'9', _rest @ ..] => {
+                    return Err(self.report_error("Decimals with leading zeros are not allowed"))
                 }
-                None => Err(self.report_error("unknown number character")),
-            },
-            [b'0', b'b' | b'B', _res @ ..] => match BIN_RE.captures(src) {
-                Some(bin) => {
-                    let bin = bin.get(1).unwrap().as_str();
-                    self.forward(bin.len());
-                    Ok(Token::new(bin, self.line, TokenKind::Number))
+                [b'0', b'_', _rest @ ..] => {
+                    return Err(self.report_error("Numeric separators are not allowed here"))
                 }
-                None => Err(self.report_error("unknown number character")),
-            },
-            _ => Ok(self.make_decimal_numbers_token(src)?),
-        }
-    }
-
-    fn make_decimal_numbers_token(&mut self, src: &str) -> LexerResult<Token> {
-        lazy_static! {
-            static ref DCLINT_RE: &'static str = "0|[1-9][0-9]*";
-            static ref EXP_RE: &'static str = "[eE][+-]?[0-9]+";
-            static ref DECIMAL1: Regex =
-                Regex::new(&format!(r"(^({0})[.][0-9]*({1})?)", *DCLINT_RE, *EXP_RE)).unwrap();
-            static ref DECIMAL2: Regex =
-                Regex::new(&format!(r"(^.[0-9]+({0})?)", *EXP_RE)).unwrap();
-            static ref DECIMAL3: Regex =
-                Regex::new(&format!(r"(^({0})({1})?)", *DCLINT_RE, *EXP_RE)).unwrap();
-        }
+                _ => {}
+            }
 
-        if self.peek().unwrap() == b'.' {
-            match DECIMAL2.captures(src) {
-                Some(decimal2) => {
-                    let decimal2 = decimal2.get(1).unwrap().as_str();
-                    self.forward(decimal2.len());
-                    return Ok(Token::new(decimal2, self.line, TokenKind::Number));
+            len += self.scan_digits(len, 10)?;
+            if self.bytes.get(len) == Some(&b'.') {
+                is_integer = false;
+                len += 1;
+                len += self.scan_digits(len, 10)?;
+            }
+            if let Some(b'e' | b'E') = self.bytes.get(len) {
+                is_integer = false;
+                len += 1;
+                if let Some(b'+' | b'-') = self.bytes.get(len) {
+                    len += 1;
+                }
+                match self.scan_digits(len, 10)? {
+                    0 => return Err(self.report_error("Digit expected")),
+                    digits => len += digits,
                 }
-                None => return Err(self.report_error("unknown decimal")),
             }
         }
 
-        match (DECIMAL1.captures(src), DECIMAL3.captures(src)) {
-            (Some(decimal1), _) => {
-                let decimal1 = decimal1.get(1).unwrap().as_str();
-                self.forward(decimal1.len());
-                Ok(Token::new(decimal1, self.line, TokenKind::Number))
-            }
-            (None, Some(decimal3)) => {
-                let decimal3 = decimal3.get(1).unwrap().as_str();
-                self.forward(decimal3.len());
-                Ok(Token::new(decimal3, self.line, TokenKind::Number))
+        let kind = match self.bytes.get(len) {
+            Some(b'n') if is_integer => {
+                len += 1;
+                TokenKind::BigInt
             }
+            Some(b'n') => return Err(self.report_error("A bigint literal must be an integer")),
+            _ => TokenKind::Number,
+        };
 
-            (None, None) => Err(self.report_error("unknown decimal")),
+        // 数字之后不能紧跟数字或标识符, 例如 0b12, 3in
+        match self.char_at(len) {
+            Some(c) if c.is_ascii_digit() => {
+                return Err(self.report_error(&format!("Invalid digit [{}] in numeric literal", c)))
+            }
+            Some(c) if c == '$' || c == '_' || c == '\\' || unicode_ident::is_xid_start(c) => {
+                return Err(self.report_error(
+                    "An identifier or keyword cannot immediately follow a numeric literal",
+                ))
+            }
+            _ => {}
         }
+
+        let number: String = str::from_utf8(&self.bytes[..len])
+            .unwrap()
+            .chars()
+            .filter(|&c| c != '_')
+            .collect();
+        self.forward(len);
+        Ok(Token::new(&number, kind))
     }
 
-    fn make_identifier_token(&mut self) -> Token {
-        lazy_static! {
-            static ref IDENTIFIER_RE: Regex = Regex::new(r"(^[_\d\w]+)").unwrap();
+    // 从 bytes[start..] 开始扫描数字和分隔符, 返回扫描过的字节数
+    fn scan_digits(&self, start: usize, radix: u32) -> LexerResult<usize> {
+        let is_digit = |b: Option<&u8>| b.is_some_and(|&b| (b as char).is_digit(radix));
+
+        let mut len = 0;
+        loop {
+            match self.bytes.get(start + len) {
+                Some(b'_') => {
+                    let next = self.bytes.get(start + len + 1);
+                    if next == Some(&b'_') {
+                        return Err(self.report_error(
+                            "Multiple consecutive numeric separators are not permitted",
+                        ));
+                    }
+                    // 分隔符前后都必须是数字
+                    if len == 0 || !is_digit(next) {
+                        return Err(self.report_error("Numeric separators are not allowed here"));
+                    }
+                    len += 1;
+                }
+                b if is_digit(b) => len += 1,
+                _ => break,
+            }
         }
+        Ok(len)
+    }
 
-        let src = str::from_utf8(self.bytes).unwrap_or("");
-        let identifier = IDENTIFIER_RE.captures(src);
-        match identifier {
-            Some(identifier) => {
-                let identifier = identifier.get(1).unwrap().as_str();
-                self.forward(identifier.len());
+    /*
+    标识符由 ID_Start 开头, 后跟任意个 ID_Continue, 另外 $ _ 以及 \u 转义也可以出现在标识符中
+    含有转义的标识符不会被识别为关键字
+    */
+    fn make_identifier_token(&mut self) -> LexerResult<Token> {
+        let mut identifier = String::new();
+        let mut escaped = false;
 
-                match KEYWORD.get(identifier) {
-                    Some(&keyword) => Token::new(identifier, self.line, keyword),
-                    None => Token::new(identifier, self.line, TokenKind::Identifier),
+        while let Some(c) = self.peek_char() {
+            match c {
+                '\\' => {
+                    if !matches!(self.bytes, [b'\\', b'u', _rest @ ..]) {
+                        return Err(self.report_error("Invalid character in identifier"));
+                    }
+                    let c = self.make_unicode_escape()?;
+                    let valid = match identifier.is_empty() {
+                        true => c == '$' || c == '_' || unicode_ident::is_xid_start(c),
+                        false => is_identifier_part(c),
+                    };
+                    if !valid {
+                        return Err(self.report_error(&format!(
+                            "Invalid character [{}] in identifier",
+                            c.escape_unicode()
+                        )));
+                    }
+                    identifier.push(c);
+                    escaped = true;
                 }
+                c if is_identifier_part(c) => {
+                    identifier.push(c);
+                    self.forward(c.len_utf8());
+                }
+                _ => break,
             }
-            None => unreachable!(),
         }
+
+        match KEYWORD.get(identifier.as_str()) {
+            Some(&keyword) if !escaped => Ok(Token::new(&identifier, keyword)),
+            _ => Ok(Token::new(&identifier, TokenKind::Identifier)),
+        }
+    }
+
+    // #name, 名字可以是关键字, 例如 #class
+    fn make_private_identifier_token(&mut self) -> LexerResult<Token> {
+        self.forward(1);
+        if !self.is_identifier_start() {
+            return Err(self.report_error("Identifier expected after '#'"));
+        }
+        let identifier = self.make_identifier_token()?;
+        Ok(Token::new(
+            &format!("#{}", identifier.peek_value()),
+            TokenKind::PrivateIdentifier,
+        ))
     }
 
     fn make_string_token(&mut self) -> LexerResult<Token> {
+        let begin = self.offset();
         let terminal = self.peek();
         let mut value = Vec::new();
 
@@ -506,49 +897,252 @@ impl<'a> Lexer<'a> {
                 }
 
                 // if the current character is a escaped character
-                Some(b'\\') => {
-                    match self.bytes {
-                        [b'\\', b'n', _rest @ ..] => value.push(b'\n'),
-                        [b'\\', b'\n', _rest @ ..] => self.inc_line(),
-                        [b'\\', b'\\', _rest @ ..] => value.push(b'\\'),
-                        [b'\\', b't', _rest @ ..] => value.push(b'\t'),
-                        [b'\\', b'r', _rest @ ..] => value.push(b'\r'),
-
-                        [b'\\', b'\'', _rest @ ..] => value.push(b'\''),
-                        [b'\\', b'\"', _rest @ ..] => value.push(b'\"'),
-                        _ => todo!(),
-                    }
-                    self.forward(2);
-                }
-                Some(b'\n') => {
-                    self.forward(1);
-                    self.inc_line();
-                }
-                Some(c) => {
+                Some(b'\\') => self.make_escape(&mut value)?,
+                Some(c) if c != b'\n' && c != b'\r' => {
                     value.push(c);
                     self.forward(1);
                 }
 
-                // if it has been the end of the string, that means error
-                None => return Err(self.report_error("Unclosed string")),
+                // 字符串不能跨行, 到达行尾或者文件末尾都说明字符串没有闭合
+                _ => return Err(self.report_error_at(begin, "Unterminated string literal")),
             }
         }
 
         Ok(Token::new(
             str::from_utf8(&value).unwrap(),
-            self.line,
             TokenKind::String,
         ))
     }
 
+    /*
+    正则表达式字面量: / body / flags
+        body 中 \ 转义下一个字符, [...] 中的 / 不结束正则, 且不能换行
+    token 的值是包括两个 / 和 flags 在内的原始文本
+    */
+    fn make_regexp_token(&mut self) -> LexerResult<Token> {
+        let mut len = 1;
+        let mut in_class = false;
+        loop {
+            match &self.bytes[len..] {
+                [b'\\', b'\n' | b'\r', ..]
+                | [b'\n' | b'\r', ..]
+                | [b'\\', 0xe2, 0x80, 0xa8 | 0xa9, ..]
+                | [0xe2, 0x80, 0xa8 | 0xa9, ..]
+                | [] => return Err(self.report_error("Unterminated regular expression literal")),
+                [b'\\', _, _rest @ ..] => len += 2,
+                [b'[', _rest @ ..] => {
+                    in_class = true;
+                    len += 1;
+                }
+                [b']', _rest @ ..] => {
+                    in_class = false;
+                    len += 1;
+                }
+                [b'/', _rest @ ..] if !in_class => {
+                    len += 1;
+                    break;
+                }
+                [_, _rest @ ..] => len += 1,
+            }
+        }
+
+        // flags
+        while let Some(b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'_' | b'$') = self.bytes.get(len)
+        {
+            len += 1;
+        }
+
+        let regexp = str::from_utf8(&self.bytes[..len]).unwrap();
+        let token = Token::new(regexp, TokenKind::RegExp);
+        self.forward(len);
+        Ok(token)
+    }
+
+    /*
+    模板字符串, 由 ` 或者闭合 `${` 的 } 开头:
+        `...`       NoSubstitutionTemplate
+        `...${      TemplateHead
+        }...${      TemplateMiddle
+        }...`       TemplateTail
+    token 的值是去掉定界符并处理转义之后的字符串
+    */
+    fn make_template_token(&mut self) -> LexerResult<Token> {
+        let begin = self.offset();
+        let is_head = self.peek() == Some(b'`');
+        if !is_head {
+            // 闭合 `${` 的 } 不再计入花括号
+            self.template_braces.pop();
+        }
+
+        let mut value = Vec::new();
+        self.forward(1);
+        let kind = loop {
+            match self.bytes {
+                [b'`', _rest @ ..] => {
+                    self.forward(1);
+                    break match is_head {
+                        true => TokenKind::NoSubstitutionTemplate,
+                        false => TokenKind::TemplateTail,
+                    };
+                }
+                [b'$', b'{', _rest @ ..] => {
+                    self.forward(2);
+                    self.template_braces.push(0);
+                    break match is_head {
+                        true => TokenKind::TemplateHead,
+                        false => TokenKind::TemplateMiddle,
+                    };
+                }
+                [b'\\', _rest @ ..] => self.make_escape(&mut value)?,
+                // 模板中的 \r\n 和 \r 都被规范化为 \n
+                [b'\r', b'\n', _rest @ ..] => {
+                    value.push(b'\n');
+                    self.forward(2);
+                }
+                [b'\n' | b'\r', _rest @ ..] => {
+                    value.push(b'\n');
+                    self.forward(1);
+                }
+                [c, _rest @ ..] => {
+                    value.push(*c);
+                    self.forward(1);
+                }
+                [] => return Err(self.report_error_at(begin, "Unterminated template literal")),
+            }
+        };
+
+        Ok(Token::new(str::from_utf8(&value).unwrap(), kind))
+    }
+
+    // 当前字符必须是 \, 将转义后的字符写入 value
+    fn make_escape(&mut self, value: &mut Vec<u8>) -> LexerResult<()> {
+        let escaped = match self.bytes {
+            [b'\\', b'u', _rest @ ..] => {
+                let c = self.make_unicode_escape()?;
+                value.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+                return Ok(());
+            }
+            [b'\\', b'x', _rest @ ..] => match self.read_hex(2, 2) {
+                Some(hex) => {
+                    self.forward(4);
+                    let c = char::from_u32(hex).unwrap();
+                    value.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+                    return Ok(());
+                }
+                None => return Err(self.report_error("Hexadecimal digit expected")),
+            },
+            // 续行, 不产生任何字符
+            [b'\\', b'\r', b'\n', _rest @ ..] => {
+                self.forward(3);
+                return Ok(());
+            }
+            [b'\\', b'\n' | b'\r', _rest @ ..] => {
+                self.forward(2);
+                return Ok(());
+            }
+            [b'\\', 0xe2, 0x80, 0xa8 | 0xa9, _rest @ ..] => {
+                self.forward(4);
+                return Ok(());
+            }
+
+            [b'\\', b'n', _rest @ ..] => b'\n',
+            [b'\\', b't', _rest @ ..] => b'\t',
+            [b'\\', b'r', _rest @ ..] => b'\r',
+            [b'\\', b'b', _rest @ ..] => 0x08,
+            [b'\\', b'f', _rest @ ..] => 0x0c,
+            [b'\\', b'v', _rest @ ..] => 0x0b,
+            [b'\\', b'0', b'0'..=b'9', _rest @ ..] | [b'\\', b'1'..=b'9', _rest @ ..] => {
+                return Err(self.report_error("Octal escape sequences are not allowed"))
+            }
+            [b'\\', b'0', _rest @ ..] => 0x00,
+
+            // 其余字符转义后就是它本身, 例如 \\ \' \" \` \$
+            [b'\\', _rest @ ..] => {
+                self.forward(1);
+                if let Some(c) = self.peek_char() {
+                    value.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+                    self.forward(c.len_utf8());
+                }
+                return Ok(());
+            }
+            _ => unreachable!(),
+        };
+        value.push(escaped);
+        self.forward(2);
+        Ok(())
+    }
+
+    /*
+    当前字符必须是 \u, 支持两种形式:
+        \uXXXX     恰好 4 位十六进制数, 两个连续的代理项会被合并为一个字符
+        \u{X...}   任意位十六进制数, 但不能超过 0x10FFFF
+    */
+    fn make_unicode_escape(&mut self) -> LexerResult<char> {
+        if let [b'\\', b'u', b'{', _rest @ ..] = self.bytes {
+            let digits = self.bytes[3..]
+                .iter()
+                .take_while(|b| b.is_ascii_hexdigit())
+                .count();
+            if digits == 0 || self.bytes.get(3 + digits) != Some(&b'}') {
+                return Err(self.report_error("Invalid Unicode escape sequence"));
+            }
+            return match self.read_hex(3, digits).and_then(char::from_u32) {
+                Some(c) => {
+                    self.forward(digits + 4);
+                    Ok(c)
+                }
+                None => Err(self.report_error(
+                    "An extended Unicode escape value must be between 0x0 and 0x10FFFF inclusive",
+                )),
+            };
+        }
+
+        let high = match self.read_hex(2, 4) {
+            Some(high) => high,
+            None => return Err(self.report_error("Invalid Unicode escape sequence")),
+        };
+        self.forward(6);
+
+        if let (0xd800..=0xdbff, [b'\\', b'u', _rest @ ..]) = (high, self.bytes) {
+            if let Some(low @ 0xdc00..=0xdfff) = self.read_hex(2, 4) {
+                self.forward(6);
+                let c = 0x10000 + ((high - 0xd800) << 10) + (low - 0xdc00);
+                return Ok(char::from_u32(c).unwrap());
+            }
+        }
+
+        // 单独的代理项无法用 char 表示
+        Ok(char::from_u32(high).unwrap_or(char::REPLACEMENT_CHARACTER))
+    }
+
+    // 从 bytes[start..] 中读取恰好 len 位十六进制数
+    fn read_hex(&self, start: usize, len: usize) -> Option<u32> {
+        let digits = self.bytes.get(start..start + len)?;
+        digits.iter().try_fold(0u32, |value, &b| {
+            let digit = (b as char).to_digit(16)?;
+            value.checked_mul(16)?.checked_add(digit)
+        })
+    }
+
     fn forward(&mut self, n: usize) {
         match n <= self.bytes.len() {
             true => self.bytes = &self.bytes[n..],
             false => self.bytes = &self.bytes[self.bytes.len()..],
         }
     }
+}
 
-    fn inc_line(&mut self) {
-        self.line += 1;
-    }
+fn is_identifier_part(c: char) -> bool {
+    matches!(c, '$' | '_' | '\u{200c}' | '\u{200d}') || unicode_ident::is_xid_continue(c)
+}
+
+fn is_whitespace(c: char) -> bool {
+    matches!(
+        c,
+        ' ' | '\t' | '\r' | '\n' | '\u{0b}' | '\u{0c}' | '\u{a0}' | '\u{feff}'
+    ) || (c > '\u{7f}' && c.is_whitespace())
+}
+
+fn is_line_terminator(c: char) -> bool {
+    matches!(c, '\n' | '\r' | '\u{2028}' | '\u{2029}')
 }
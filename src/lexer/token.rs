@@ -1,38 +1,110 @@
 use colored::Colorize;
 use std::fmt::{Display, Formatter};
 
-use super::token_kind::TokenKind;
+use crate::ast::{Pos, Span};
 
-#[derive(Debug)]
+use super::{token_kind::TokenKind, trivia::Trivia};
+
+#[derive(Debug, PartialEq)]
 pub struct Token {
     value: String,
-    line: usize,
+    span: Span,
     kind: TokenKind,
+
+    // 只有 Lexer 开启 keep_trivia 时才会收集
+    leading_trivia: Vec<Trivia>,
+    trailing_trivia: Vec<Trivia>,
 }
 
 impl Token {
-    pub fn new(value: &str, line: usize, kind: TokenKind) -> Token {
+    // span 由 Lexer 在扫描完整个 token 之后设置
+    pub fn new(value: &str, kind: TokenKind) -> Token {
         Token {
-            line,
+            span: Span::default(),
             kind,
             value: String::from(value),
+            leading_trivia: Vec::new(),
+            trailing_trivia: Vec::new(),
         }
     }
 
+    pub(crate) fn set_span(&mut self, span: Span) {
+        self.span = span;
+    }
+
+    // 对 token 及其 trivia 的所有 span 做同样的变换, 用于增量词法分析中平移旧 token
+    pub(crate) fn map_span(&mut self, f: impl Fn(Span) -> Span) {
+        self.span = f(self.span);
+        for trivia in self
+            .leading_trivia
+            .iter_mut()
+            .chain(&mut self.trailing_trivia)
+        {
+            trivia.set_span(f(trivia.peek_span()));
+        }
+    }
+
+    // 类型实参中的 >> 需要拆成两个 >, 例如 Array<Array<T>>
+    // 该 token 只保留开头的 >, 返回剩下的部分
+    pub(crate) fn split_more_than(&mut self, rest_kind: TokenKind) -> Token {
+        let mid = Pos::new(
+            self.span.begin.offset + 1,
+            self.span.begin.line,
+            self.span.begin.column + 1,
+        );
+        let mut rest = Token::new(&self.value[1..], rest_kind);
+        rest.span = Span::new(mid, self.span.end);
+        rest.trailing_trivia = std::mem::take(&mut self.trailing_trivia);
+
+        self.value.truncate(1);
+        self.kind = TokenKind::MoreThan;
+        self.span.end = mid;
+        rest
+    }
+
+    pub(crate) fn set_trivia(&mut self, leading: Vec<Trivia>, trailing: Vec<Trivia>) {
+        self.leading_trivia = leading;
+        self.trailing_trivia = trailing;
+    }
+
     pub(crate) fn kind_is(&self, kind: TokenKind) -> bool {
         self.kind == kind
     }
 
-    pub(crate) fn peek_kind(&self) -> TokenKind {
+    pub fn peek_kind(&self) -> TokenKind {
         self.kind
     }
 
-    pub(crate) fn peek_value(&self) -> &str {
+    pub fn peek_value(&self) -> &str {
         &self.value
     }
 
-    pub(crate) fn peek_line(&self) -> usize {
-        self.line
+    pub fn peek_line(&self) -> usize {
+        self.span.begin.line
+    }
+
+    pub fn peek_begin(&self) -> Pos {
+        self.span.begin
+    }
+
+    pub fn peek_end(&self) -> Pos {
+        self.span.end
+    }
+
+    pub fn peek_leading_trivia(&self) -> &[Trivia] {
+        &self.leading_trivia
+    }
+
+    pub fn peek_trailing_trivia(&self) -> &[Trivia] {
+        &self.trailing_trivia
+    }
+
+    // 稳定的单行文本格式, 用于 token 流的 dump 和快照测试:
+    //     begin-end  kind  "value"
+    pub(crate) fn dump(&self) -> String {
+        let span = format!("{}-{}", self.span.begin, self.span.end);
+        let kind = format!("{:?}", self.kind);
+        format!("{:<12} {:<24} {:?}", span, kind, self.value)
     }
 }
 
@@ -40,8 +112,8 @@ impl Display for Token {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "[{:>5}][{}] {}",
-            self.line,
+            "[{:>9}][{}] {}",
+            self.span.begin.to_string(),
             self.kind,
             self.value.yellow()
         )
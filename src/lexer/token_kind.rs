@@ -73,6 +73,15 @@ pub enum KeyWordKind {
     From,        // from
     ReadOnly,    // readonly
     Async,       // async
+    Keyof,       // keyof
+    Unknown,     // unknown
+    Never,       // never
+    Undefined,   // undefined
+    Object,      // object
+    BigInt,      // bigint
+    Infer,       // infer
+    Await,       // await
+    Of,          // of
 
     ///,    Future Reserved Words
     Class, // class
@@ -144,6 +153,15 @@ impl Visualizable for KeyWordKind {
             KeyWordKind::From => graph.put_node(info, "from"),
             KeyWordKind::ReadOnly => graph.put_node(info, "readonly"),
             KeyWordKind::Async => graph.put_node(info, "async"),
+            KeyWordKind::Keyof => graph.put_node(info, "keyof"),
+            KeyWordKind::Unknown => graph.put_node(info, "unknown"),
+            KeyWordKind::Never => graph.put_node(info, "never"),
+            KeyWordKind::Undefined => graph.put_node(info, "undefined"),
+            KeyWordKind::Object => graph.put_node(info, "object"),
+            KeyWordKind::BigInt => graph.put_node(info, "bigint"),
+            KeyWordKind::Infer => graph.put_node(info, "infer"),
+            KeyWordKind::Await => graph.put_node(info, "await"),
+            KeyWordKind::Of => graph.put_node(info, "of"),
             KeyWordKind::Class => graph.put_node(info, "class"),
             KeyWordKind::Enum => graph.put_node(info, "enum"),
             KeyWordKind::Extends => graph.put_node(info, "extends"),
@@ -217,6 +235,15 @@ impl Display for KeyWordKind {
             KeyWordKind::From => token_string!(f, "keyworld-from"),
             KeyWordKind::ReadOnly => token_string!(f, "keyworld-readonly"),
             KeyWordKind::Async => token_string!(f, "keyworld-async"),
+            KeyWordKind::Keyof => token_string!(f, "keyworld-keyof"),
+            KeyWordKind::Unknown => token_string!(f, "keyworld-unknown"),
+            KeyWordKind::Never => token_string!(f, "keyworld-never"),
+            KeyWordKind::Undefined => token_string!(f, "keyworld-undefined"),
+            KeyWordKind::Object => token_string!(f, "keyworld-object"),
+            KeyWordKind::BigInt => token_string!(f, "keyworld-bigint"),
+            KeyWordKind::Infer => token_string!(f, "keyworld-infer"),
+            KeyWordKind::Await => token_string!(f, "keyworld-await"),
+            KeyWordKind::Of => token_string!(f, "keyworld-of"),
             KeyWordKind::Class => token_string!(f, "keyworld-class"),
             KeyWordKind::Enum => token_string!(f, "keyworld-enum"),
             KeyWordKind::Extends => token_string!(f, "keyworld-extends"),
@@ -231,9 +258,19 @@ impl Display for KeyWordKind {
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub enum TokenKind {
     Identifier,
+    PrivateIdentifier, // #name
     KeyWord(KeyWordKind),
     Number,
+    BigInt, // 10n
     String,
+    RegExp, // /pattern/flags
+
+    NoSubstitutionTemplate, // `...`
+    TemplateHead,           // `...${
+    TemplateMiddle,         // }...${
+    TemplateTail,           // }...`
+
+    JsxText, // JSX 子节点中的文本
 
     At, // @
 
@@ -247,12 +284,18 @@ pub enum TokenKind {
     SemiColon,    // ;
     QuestionMark, // ?
     Colon,        // :
-    BitNot,       // ~
+
+    QuestionDot,        // ?.
+    NullCoalesceAssign, // ??=
+    NullCoalesce,       // ??
+    BitNot,             // ~
 
     IdentityNotEquals, // !==
     NotEquals,         // !=
     Not,               // !
 
+    PowerAssign,    // **=
+    Power,          // **
     MultiplyAssign, // *=
     Multiply,       // *
 
@@ -290,6 +333,7 @@ pub enum TokenKind {
     Arrow,          // =>
     Assign,         // =
 
+    AndAssign,    // &&=
     And,          // &&
     BitAndAssign, // &=
     BitAnd,       // &
@@ -298,20 +342,84 @@ pub enum TokenKind {
     BitXOr,       // ^
 
     BitOrAssign, // |=
+    OrAssign,    // ||=
     Or,          // ||
     BitOr,       // |
 
+    Error, // 词法错误, 值为出错时跳过的原始文本
     EOF,
 }
 
+impl TokenKind {
+    /*
+    上下文关键字只在特定的位置才有特殊含义, 其余位置都可以作为标识符, 例如 let type = 1, obj.get()
+    其余的关键字都是保留字, 其中 let yield static implements interface package
+    private protected public 在严格模式下是保留字, 而 TS 的模块和类总是处于严格模式
+    */
+    pub(crate) fn is_contextual_keyword(&self) -> bool {
+        matches!(
+            self,
+            TokenKind::KeyWord(
+                KeyWordKind::Any
+                    | KeyWordKind::Number
+                    | KeyWordKind::Boolean
+                    | KeyWordKind::String
+                    | KeyWordKind::Symbol
+                    | KeyWordKind::Type
+                    | KeyWordKind::Get
+                    | KeyWordKind::Set
+                    | KeyWordKind::Constructor
+                    | KeyWordKind::Namespace
+                    | KeyWordKind::Require
+                    | KeyWordKind::Module
+                    | KeyWordKind::Declare
+                    | KeyWordKind::Abstract
+                    | KeyWordKind::Is
+                    | KeyWordKind::As
+                    | KeyWordKind::From
+                    | KeyWordKind::ReadOnly
+                    | KeyWordKind::Async
+                    | KeyWordKind::Keyof
+                    | KeyWordKind::Unknown
+                    | KeyWordKind::Never
+                    | KeyWordKind::Undefined
+                    | KeyWordKind::Object
+                    | KeyWordKind::BigInt
+                    | KeyWordKind::Infer
+                    | KeyWordKind::Await
+                    | KeyWordKind::Of
+            )
+        )
+    }
+
+    // 可以作为标识符使用的 token
+    pub(crate) fn is_identifier(&self) -> bool {
+        *self == TokenKind::Identifier || self.is_contextual_keyword()
+    }
+
+    // 属性名的位置上保留字也可以使用, 例如 obj.delete(), { class: 1 }
+    pub(crate) fn is_identifier_name(&self) -> bool {
+        matches!(self, TokenKind::Identifier | TokenKind::KeyWord(_))
+    }
+}
+
 impl Visualizable for TokenKind {
     fn draw(&self, info: NodeInfo, graph: &mut AstGraph) {
         match self {
             // 这些 token 不可能到达,我们需要单独处理他们
             TokenKind::Identifier
+            | TokenKind::PrivateIdentifier
             | TokenKind::KeyWord(_)
             | TokenKind::Number
+            | TokenKind::BigInt
             | TokenKind::String
+            | TokenKind::RegExp
+            | TokenKind::NoSubstitutionTemplate
+            | TokenKind::TemplateHead
+            | TokenKind::TemplateMiddle
+            | TokenKind::TemplateTail
+            | TokenKind::JsxText
+            | TokenKind::Error
             | TokenKind::EOF => unreachable!(),
 
             TokenKind::At => graph.put_node(info, "@"),
@@ -325,10 +433,15 @@ impl Visualizable for TokenKind {
             TokenKind::SemiColon => graph.put_node(info, ";"),
             TokenKind::QuestionMark => graph.put_node(info, "?"),
             TokenKind::Colon => graph.put_node(info, ":"),
+            TokenKind::QuestionDot => graph.put_node(info, "?."),
+            TokenKind::NullCoalesceAssign => graph.put_node(info, "??="),
+            TokenKind::NullCoalesce => graph.put_node(info, "??"),
             TokenKind::BitNot => graph.put_node(info, "~"),
             TokenKind::IdentityNotEquals => graph.put_node(info, "!=="),
             TokenKind::NotEquals => graph.put_node(info, "!="),
             TokenKind::Not => graph.put_node(info, "!"),
+            TokenKind::PowerAssign => graph.put_node(info, "**="),
+            TokenKind::Power => graph.put_node(info, "**"),
             TokenKind::MultiplyAssign => graph.put_node(info, "*="),
             TokenKind::Multiply => graph.put_node(info, "*"),
             TokenKind::DivideAssign => graph.put_node(info, "/="),
@@ -357,12 +470,14 @@ impl Visualizable for TokenKind {
             TokenKind::Equals => graph.put_node(info, "=="),
             TokenKind::Arrow => graph.put_node(info, "=>"),
             TokenKind::Assign => graph.put_node(info, "="),
+            TokenKind::AndAssign => graph.put_node(info, "&&="),
             TokenKind::And => graph.put_node(info, "&&"),
             TokenKind::BitAndAssign => graph.put_node(info, "&="),
             TokenKind::BitAnd => graph.put_node(info, "&"),
             TokenKind::BitXorAssign => graph.put_node(info, "^="),
             TokenKind::BitXOr => graph.put_node(info, "^"),
             TokenKind::BitOrAssign => graph.put_node(info, "|="),
+            TokenKind::OrAssign => graph.put_node(info, "||="),
             TokenKind::Or => graph.put_node(info, "||"),
             TokenKind::BitOr => graph.put_node(info, "|"),
         }
@@ -373,10 +488,19 @@ impl Display for TokenKind {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
             TokenKind::Identifier => token_string!(f, "identifier"),
+            TokenKind::PrivateIdentifier => token_string!(f, "private identifier"),
             TokenKind::KeyWord(kind) => token_string!(f, kind),
 
             TokenKind::Number => token_string!(f, "number"),
+            TokenKind::BigInt => token_string!(f, "bigint"),
             TokenKind::String => token_string!(f, "string"),
+            TokenKind::RegExp => token_string!(f, "regexp"),
+
+            TokenKind::NoSubstitutionTemplate => token_string!(f, "template"),
+            TokenKind::TemplateHead => token_string!(f, "template head"),
+            TokenKind::TemplateMiddle => token_string!(f, "template middle"),
+            TokenKind::TemplateTail => token_string!(f, "template tail"),
+            TokenKind::JsxText => token_string!(f, "jsx text"),
 
             TokenKind::At => token_string!(f, "at"),
             TokenKind::LeftParen => token_string!(f, "left paren"),
@@ -390,12 +514,17 @@ impl Display for TokenKind {
             TokenKind::SemiColon => token_string!(f, "semicolon"),
             TokenKind::QuestionMark => token_string!(f, "question mark"),
             TokenKind::Colon => token_string!(f, "colon"),
+            TokenKind::QuestionDot => token_string!(f, "question dot"),
+            TokenKind::NullCoalesceAssign => token_string!(f, "null coalesce assign"),
+            TokenKind::NullCoalesce => token_string!(f, "null coalesce"),
             TokenKind::BitNot => token_string!(f, "bit not"),
 
             TokenKind::IdentityNotEquals => token_string!(f, "identity not equals"),
             TokenKind::NotEquals => token_string!(f, "not equals"),
             TokenKind::Not => token_string!(f, "not"),
 
+            TokenKind::PowerAssign => token_string!(f, "power assign"),
+            TokenKind::Power => token_string!(f, "power"),
             TokenKind::MultiplyAssign => token_string!(f, "multiply assign"),
             TokenKind::Multiply => token_string!(f, "multiply"),
 
@@ -433,6 +562,7 @@ impl Display for TokenKind {
             TokenKind::Arrow => token_string!(f, "arrow"),
             TokenKind::Assign => token_string!(f, "assign"),
 
+            TokenKind::AndAssign => token_string!(f, "and assign"),
             TokenKind::And => token_string!(f, "and"),
             TokenKind::BitAndAssign => token_string!(f, "bit and assign"),
             TokenKind::BitAnd => token_string!(f, "bit and"),
@@ -441,9 +571,11 @@ impl Display for TokenKind {
             TokenKind::BitXOr => token_string!(f, "bit xor"),
 
             TokenKind::BitOrAssign => token_string!(f, "bit or assign"),
+            TokenKind::OrAssign => token_string!(f, "or assign"),
             TokenKind::Or => token_string!(f, "or"),
             TokenKind::BitOr => token_string!(f, "bit or"),
 
+            TokenKind::Error => token_string!(f, "error"),
             TokenKind::EOF => token_string!(f, "EOF"),
         }
     }
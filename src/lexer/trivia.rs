@@ -0,0 +1,182 @@
+use crate::ast::Span;
+
+/*
+trivia 是 token 之间与语法无关的部分: 空白, 换行, 注释
+只有在 Lexer 开启 keep_trivia 时才会收集, 并挂在 token 上:
+    leading  从上一个 token 的 trailing 结束处, 到该 token 开始处
+    trailing 从该 token 结束处, 到同一行的换行之前
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub enum TriviaKind {
+    Whitespace,        // 空格, 制表符等
+    NewLine,           // \n \r\n \r U+2028 U+2029
+    Hashbang,          // #!... 只能出现在文件开头
+    SingleLineComment, // // ...
+    MultiLineComment,  // /* ... */
+    JsDoc(JsDoc),      // /** ... */
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Trivia {
+    kind: TriviaKind,
+    text: String,
+    span: Span,
+}
+
+impl Trivia {
+    pub(crate) fn new(kind: TriviaKind, text: &str, span: Span) -> Self {
+        Self {
+            kind,
+            text: text.to_string(),
+            span,
+        }
+    }
+
+    pub fn peek_kind(&self) -> &TriviaKind {
+        &self.kind
+    }
+
+    pub fn peek_text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn peek_span(&self) -> Span {
+        self.span
+    }
+
+    pub(crate) fn set_span(&mut self, span: Span) {
+        self.span = span;
+    }
+
+    pub fn is_comment(&self) -> bool {
+        !matches!(self.kind, TriviaKind::Whitespace | TriviaKind::NewLine)
+    }
+}
+
+// JSDoc 是以 /** 开头的多行注释, 由描述和若干 tag 组成, 每个 tag 的格式为:
+//     @tag {type} name comment
+// 其中 {type} 和 name 都是可选的
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct JsDoc {
+    description: String,
+    tags: Vec<JsDocTag>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct JsDocTag {
+    tag: String,
+    type_expr: Option<String>,
+    name: Option<String>,
+    comment: String,
+}
+
+impl JsDoc {
+    // text 是包括 /** 和 */ 在内的完整注释
+    pub(crate) fn parse(text: &str) -> Self {
+        let body = text
+            .strip_prefix("/**")
+            .and_then(|body| body.strip_suffix("*/"))
+            .unwrap_or(text);
+
+        let mut jsdoc = JsDoc::default();
+        let mut description = Vec::new();
+        let mut tag_lines: Vec<Vec<&str>> = Vec::new();
+
+        for line in body.lines() {
+            // 去掉每行开头的 * 以及紧跟的一个空格
+            let line = line.trim_start();
+            let line = line.strip_prefix('*').unwrap_or(line);
+            let line = line.strip_prefix(' ').unwrap_or(line).trim_end();
+
+            if line.starts_with('@') {
+                tag_lines.push(vec![line]);
+            } else if let Some(lines) = tag_lines.last_mut() {
+                lines.push(line);
+            } else {
+                description.push(line);
+            }
+        }
+
+        jsdoc.description = description.join("\n").trim().to_string();
+        jsdoc.tags = tag_lines
+            .iter()
+            .map(|lines| JsDocTag::parse(&lines.join("\n")))
+            .collect();
+        jsdoc
+    }
+
+    pub fn peek_description(&self) -> &str {
+        &self.description
+    }
+
+    pub fn peek_tags(&self) -> &[JsDocTag] {
+        &self.tags
+    }
+}
+
+impl JsDocTag {
+    // text 以 @ 开头
+    fn parse(text: &str) -> Self {
+        let mut jsdoc_tag = JsDocTag::default();
+
+        let text = &text[1..];
+        let tag_len = text
+            .find(|c: char| c.is_whitespace() || c == '{')
+            .unwrap_or(text.len());
+        jsdoc_tag.tag = text[..tag_len].to_string();
+        let mut rest = text[tag_len..].trim_start();
+
+        // {type}, 类型中可能嵌套花括号, 例如 {{a: number}}
+        if rest.starts_with('{') {
+            let mut depth = 0;
+            for (i, c) in rest.char_indices() {
+                match c {
+                    '{' => depth += 1,
+                    '}' => depth -= 1,
+                    _ => continue,
+                }
+                if depth == 0 {
+                    jsdoc_tag.type_expr = Some(rest[1..i].trim().to_string());
+                    rest = rest[i + 1..].trim_start();
+                    break;
+                }
+            }
+        }
+
+        // 只有这些 tag 后面跟着名字, 可选参数写作 [name] 或 [name=default]
+        if matches!(
+            jsdoc_tag.tag.as_str(),
+            "param" | "arg" | "argument" | "property" | "prop" | "template" | "typedef"
+        ) {
+            let name_len = match rest.starts_with('[') {
+                true => rest.find(']').map_or(rest.len(), |i| i + 1),
+                false => rest.find(char::is_whitespace).unwrap_or(rest.len()),
+            };
+            if name_len > 0 {
+                jsdoc_tag.name = Some(rest[..name_len].to_string());
+                rest = rest[name_len..].trim_start();
+            }
+        }
+
+        // @param name - comment
+        let rest = rest.strip_prefix("- ").unwrap_or(rest);
+        jsdoc_tag.comment = rest.trim().to_string();
+        jsdoc_tag
+    }
+
+    pub fn peek_tag(&self) -> &str {
+        &self.tag
+    }
+
+    pub fn peek_type_expr(&self) -> Option<&str> {
+        self.type_expr.as_deref()
+    }
+
+    pub fn peek_name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    pub fn peek_comment(&self) -> &str {
+        &self.comment
+    }
+}
@@ -3,19 +3,32 @@ use kts::{compiler::Compiler, error::err_exit};
 use std::{env, process};
 
 fn main() {
-    let mut args = env::args();
-    if args.len() != 2 {
-        eprintln!("{}", String::from("Usage: kts <filename>").blue().bold());
-        process::exit(1);
-    }
+    let args: Vec<String> = env::args().collect();
+    // kts --tokens <filename> 只打印 token 流
+    let (filename, dump_tokens) = match args.as_slice() {
+        [_, filename] => (filename, false),
+        [_, flag, filename] if flag == "--tokens" => (filename, true),
+        _ => {
+            eprintln!(
+                "{}",
+                String::from("Usage: kts [--tokens] <filename>")
+                    .blue()
+                    .bold()
+            );
+            process::exit(1);
+        }
+    };
     // 通过 cargo 命令行启动时当前路径是在 src 下
     // 通过测试启动时在 project 文件夹下
-    let filename = args.nth(1).unwrap();
-    let compiler = Compiler::new(&filename);
+    let mut compiler = Compiler::new(filename);
+    if dump_tokens {
+        compiler = compiler.set_dump_tokens();
+    }
     let res = compiler.run();
 
     match res {
         Err(e) => err_exit(e),
+        Ok(()) if dump_tokens => {}
         Ok(()) => println!("{}", "Compiliation succeeded !!!".green().bold()),
     }
 }
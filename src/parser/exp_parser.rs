@@ -4,6 +4,7 @@ use crate::{
             decl::{ArrowFuncExpDecl, ClassExp},
             exp::*,
             identifier::Identifier,
+            literal::Literal,
         },
         ASTNode, Span,
     },
@@ -16,6 +17,26 @@ impl Parser {
     pub(super) fn parse_exp(&mut self) -> ParseResult<ASTNode<Exp>> {
         let begin = self.mark_begin();
 
+        // yield 和赋值处于同一优先级, 例如 x = yield y
+        if self.kind_is(TokenKind::KeyWord(KeyWordKind::Yield)) {
+            return self.parse_yield_exp();
+        }
+
+        // [a, b] = [b, a]
+        if self.is_assign_pattern() {
+            let pattern = self.parse_assign_pattern()?.ctx();
+            let left = ASTNode::new(
+                Exp::BindingPattern(pattern),
+                Span::new(begin, self.mark_end()),
+            );
+            let assign_op = self.extract_op()?;
+            let right = self.parse_exp()?;
+            return Ok(ASTNode::new(
+                Exp::AssignExp(AssignExp::new(left, assign_op, right)),
+                Span::new(begin, self.mark_end()),
+            ));
+        }
+
         let mut left = self.parse_single_exp()?;
         if self.is_assign_op() {
             let assign_op = self.extract_op()?;
@@ -29,10 +50,40 @@ impl Parser {
         Ok(left)
     }
 
+    /*
+    yieldExpression: Yield ({this.notLineTerminator()}? '*'? expression)?;
+    */
+    fn parse_yield_exp(&mut self) -> ParseResult<ASTNode<Exp>> {
+        let begin = self.mark_begin();
+
+        let mut yield_exp = YieldExp::default();
+        self.eat(TokenKind::KeyWord(KeyWordKind::Yield))?;
+        if self.kind_is(TokenKind::Multiply) && !self.is_new_line() {
+            self.forward();
+            yield_exp.set_delegate();
+            yield_exp.set_exp(self.parse_exp()?);
+        }
+        // 单独的 yield 后面可以直接跟 ; ) ] } , : 或者换行
+        else if !self.is_eos()
+            && !matches!(
+                self.peek_kind(),
+                TokenKind::RightParen | TokenKind::RightBrace | TokenKind::Comma | TokenKind::Colon
+            )
+        {
+            yield_exp.set_exp(self.parse_exp()?);
+        }
+
+        Ok(ASTNode::new(
+            Exp::YieldExp(yield_exp),
+            Span::new(begin, self.mark_end()),
+        ))
+    }
+
     /*
     不包含赋值语句的表达式
     single_exp:
         primary
+        | single_exp ** single_exp
         | single_exp % single_exp
         | single_exp / single_exp
         | single_exp * single_exp
@@ -62,9 +113,10 @@ impl Parser {
         | single_exp | single_exp
         | single_exp && single_exp
         | single_exp || single_exp
-        | single_exp ? single_exp : single_exp   // 三元表达式
+        | single_exp ?? single_exp
+        | single_exp ? exp : exp   // 三元表达式, 两个分支都是赋值表达式, 例如 a ? yield 1 : b
     */
-    fn parse_single_exp(&mut self) -> ParseResult<ASTNode<Exp>> {
+    pub(super) fn parse_single_exp(&mut self) -> ParseResult<ASTNode<Exp>> {
         let mut exp_stack = Vec::new();
         let mut op_stack = Vec::new();
 
@@ -76,6 +128,11 @@ impl Parser {
             }
             // 由于不是 single_exp_op 的都 break 了，此处提取出来的必是 single_exp_op
             let op = self.extract_op()?;
+            // 三元表达式的优先级最低, ? 之前的部分都属于条件
+            if op == Op::QuestionMark {
+                let cond = self.extract_exp_from_stack(op_stack, exp_stack)?;
+                return self.parse_ternary_exp(cond);
+            }
             self.push_op(&mut op_stack, &mut exp_stack, op)?;
 
             // 特殊的 as cast operator
@@ -99,7 +156,12 @@ impl Parser {
         let begin = self.mark_begin();
 
         let prefix = self.extract_prefix_op();
-        let base_exp = self.parse_base_exp()?;
+        let base_exp = if self.kind_is(TokenKind::KeyWord(KeyWordKind::Await)) {
+            self.parse_await_exp()?
+        } else {
+            // 括号, 调用参数和下标中又可以使用 in 了, 例如 for (let i = (a in b); ...)
+            self.allow_in(true, Parser::parse_base_exp)?
+        };
         let postfix = self.extract_postfix_op();
 
         if prefix.is_none() && postfix.is_none() {
@@ -116,7 +178,22 @@ impl Parser {
         }
     }
 
-    // . [] ()
+    /*
+    awaitExpression: Await unary;
+    await a + b 等价于 (await a) + b, await a.b() 等价于 await (a.b())
+    */
+    fn parse_await_exp(&mut self) -> ParseResult<ASTNode<Exp>> {
+        let begin = self.mark_begin();
+
+        self.eat(TokenKind::KeyWord(KeyWordKind::Await))?;
+        let exp = self.parse_unary_exp()?;
+        Ok(ASTNode::new(
+            Exp::AwaitExp(AwaitExp::new(exp)),
+            Span::new(begin, self.mark_end()),
+        ))
+    }
+
+    // . [] () ?. ?.[] ?.()
     fn parse_base_exp(&mut self) -> ParseResult<ASTNode<Exp>> {
         let begin = self.mark_begin();
 
@@ -127,21 +204,8 @@ impl Parser {
         exp_stack.push(atom_exp);
 
         loop {
-            if self.kind_is(TokenKind::LeftParen) {
-                self.eat(TokenKind::LeftParen)?;
-                // 函数调用有可能无参数
-                let args_exp = if self.kind_is(TokenKind::RightParen) {
-                    ASTNode::new(
-                        Exp::ArgsExp(ArgsExp::default()),
-                        Span::new(begin, self.mark_end()),
-                    )
-                } else {
-                    ASTNode::new(
-                        Exp::ArgsExp(ArgsExp::new(self.parse_exp_seq()?)),
-                        Span::new(begin, self.mark_end()),
-                    )
-                };
-                self.eat(TokenKind::RightParen)?;
+            if self.kind_is(TokenKind::LeftParen) || self.is_call_type_args() {
+                let args_exp = self.parse_args_exp()?;
 
                 self.push_op(&mut op_stack, &mut exp_stack, Op::Call)?;
                 exp_stack.push(args_exp);
@@ -155,10 +219,39 @@ impl Parser {
                 exp_stack.push(index_exp);
             } else if self.kind_is(TokenKind::Dot) {
                 self.eat(TokenKind::Dot)?;
-                let other_atom_exp = self.parse_atom_exp()?;
+                let other_atom_exp = self.parse_property_name_exp()?;
 
                 self.push_op(&mut op_stack, &mut exp_stack, Op::Dot)?;
                 exp_stack.push(other_atom_exp);
+            } else if self.kind_is(TokenKind::QuestionDot) {
+                self.eat(TokenKind::QuestionDot)?;
+                let (op, exp) = match self.peek_kind() {
+                    // a?.(...)
+                    TokenKind::LeftParen => (Op::OptionalCall, self.parse_args_exp()?),
+                    // a?.[...]
+                    TokenKind::LeftBrace => {
+                        self.eat(TokenKind::LeftBrace)?;
+                        let index_exp = self.parse_exp()?;
+                        self.eat(TokenKind::RightBrace)?;
+                        (Op::OptionalIndex, index_exp)
+                    }
+                    // a?.b
+                    _ => (Op::OptionalDot, self.parse_property_name_exp()?),
+                };
+
+                self.push_op(&mut op_stack, &mut exp_stack, op)?;
+                exp_stack.push(exp);
+            } else if self.is_template_begin() {
+                // tag`...`, 模板之前的部分整体作为 tag
+                let tag = self.extract_exp_from_stack(
+                    std::mem::take(&mut op_stack),
+                    std::mem::take(&mut exp_stack),
+                )?;
+                let template = self.parse_template_exp()?;
+                exp_stack.push(ASTNode::new(
+                    Exp::TaggedTemplateExp(TaggedTemplateExp::new(tag, template)),
+                    Span::new(begin, self.mark_end()),
+                ));
             } else {
                 break;
             }
@@ -167,11 +260,118 @@ impl Parser {
         self.extract_exp_from_stack(op_stack, exp_stack)
     }
 
+    // typeArguments? ( (exp (',' exp)*)? )
+    fn parse_args_exp(&mut self) -> ParseResult<ASTNode<Exp>> {
+        let begin = self.mark_begin();
+
+        let mut type_args = None;
+        if self.kind_is(TokenKind::LessThan) {
+            type_args = Some(self.parse_type_args()?);
+        }
+
+        self.eat(TokenKind::LeftParen)?;
+        // 函数调用有可能无参数
+        let mut args_exp = if self.kind_is(TokenKind::RightParen) {
+            ArgsExp::default()
+        } else {
+            ArgsExp::new(self.parse_arg_seq()?)
+        };
+        self.eat(TokenKind::RightParen)?;
+
+        if let Some(type_args) = type_args {
+            args_exp.set_type_args(type_args);
+        }
+
+        Ok(ASTNode::new(
+            Exp::ArgsExp(args_exp),
+            Span::new(begin, self.mark_end()),
+        ))
+    }
+
+    // argument (',' argument)* ','?, 最后可以有多余的逗号, 例如 f(a, b,)
+    fn parse_arg_seq(&mut self) -> ParseResult<ASTNode<ExpSeq>> {
+        let begin = self.mark_begin();
+        let mut arg_seq = ExpSeq::default();
+        loop {
+            arg_seq.push_exp(self.parse_arg_exp()?);
+            if !self.kind_is(TokenKind::Comma) {
+                break;
+            }
+            self.forward();
+            if self.kind_is(TokenKind::RightParen) {
+                break;
+            }
+        }
+        Ok(ASTNode::new(arg_seq, Span::new(begin, self.mark_end())))
+    }
+
+    // 函数参数和数组元素: '...'? singleExpression
+    fn parse_arg_exp(&mut self) -> ParseResult<ASTNode<Exp>> {
+        let begin = self.mark_begin();
+        if !self.kind_is(TokenKind::Ellipsis) {
+            return self.parse_exp();
+        }
+
+        self.eat(TokenKind::Ellipsis)?;
+        Ok(ASTNode::new(
+            Exp::SpreadElement(SpreadElement::new(self.parse_exp()?)),
+            Span::new(begin, self.mark_end()),
+        ))
+    }
+
+    // . 之后的属性名, 保留字也可以作为属性名, 例如 a.delete, 类中还可以访问私有名, 例如 this.#x
+    fn parse_property_name_exp(&mut self) -> ParseResult<ASTNode<Exp>> {
+        let begin = self.mark_begin();
+        let identifier = self.parse_member_name()?.ctx();
+        Ok(ASTNode::new(
+            Exp::Identifier(identifier),
+            Span::new(begin, self.mark_end()),
+        ))
+    }
+
     fn parse_atom_exp(&mut self) -> ParseResult<ASTNode<Exp>> {
         let begin = self.mark_begin();
 
         match self.peek_kind() {
-            TokenKind::Identifier => match self.next_kind() {
+            // #x in obj
+            TokenKind::PrivateIdentifier
+                if self.nextkind_is(TokenKind::KeyWord(KeyWordKind::In)) =>
+            {
+                Ok(ASTNode::new(
+                    Exp::Identifier(self.parse_member_name()?.ctx()),
+                    Span::new(begin, self.mark_end()),
+                ))
+            }
+
+            // async function ... 或者 async function* ...
+            TokenKind::KeyWord(KeyWordKind::Async)
+                if self.is_async_prefix()
+                    && self.nextkind_is(TokenKind::KeyWord(KeyWordKind::Function)) =>
+            {
+                let exp = if self.lookahead(2) == TokenKind::Multiply {
+                    Exp::GeneratorExp(self.parse_generator_func_decl()?.ctx())
+                } else {
+                    Exp::FunctionExp(self.parse_func_exp_decl()?.ctx())
+                };
+                Ok(ASTNode::new(exp, Span::new(begin, self.mark_end())))
+            }
+
+            // async (a) => ..., async a => ..., 不是箭头函数时 async 只是普通的标识符, 例如 async(a)
+            TokenKind::KeyWord(KeyWordKind::Async) if self.is_async_prefix() => {
+                match self.try_to(Parser::parse_arrow_func) {
+                    Some(arrow_func) => Ok(ASTNode::new(
+                        Exp::ArrowFuncExp(arrow_func.ctx()),
+                        Span::new(begin, self.mark_end()),
+                    )),
+
+                    None => Ok(ASTNode::new(
+                        Exp::Identifier(self.parse_identifier()?.ctx()),
+                        Span::new(begin, self.mark_end()),
+                    )),
+                }
+            }
+
+            _ if self.is_identifier() => match self.next_kind() {
                 // 如果是 a => ...
                 TokenKind::Arrow => Ok(ASTNode::new(
                     Exp::ArrowFuncExp(self.parse_arrow_func()?.ctx()),
@@ -189,12 +389,26 @@ impl Parser {
                 Span::new(begin, self.mark_end()),
             )),
 
+            _ if self.is_template_begin() => Ok(ASTNode::new(
+                Exp::TemplateExp(self.parse_template_exp()?.ctx()),
+                Span::new(begin, self.mark_end()),
+            )),
+
+            // <T>(a: T) => a, tsx 中 <T> 是 JSX 标签, 泛型箭头函数需要写成 <T,> 或者 <T extends U>
+            TokenKind::LessThan if !self.jsx || self.is_generic_arrow() => Ok(ASTNode::new(
+                Exp::ArrowFuncExp(self.parse_arrow_func()?.ctx()),
+                Span::new(begin, self.mark_end()),
+            )),
+
+            // <div>...</div>
+            TokenKind::LessThan => self.parse_jsx_exp(),
+
             // Class Identifier? classTail
             TokenKind::KeyWord(KeyWordKind::Class) => {
                 self.eat(TokenKind::KeyWord(KeyWordKind::Class))?;
 
                 let mut class_exp = ClassExp::default();
-                if self.kind_is(TokenKind::Identifier) {
+                if self.is_identifier() {
                     class_exp.set_class_name(self.parse_identifier()?);
                 }
 
@@ -234,7 +448,7 @@ impl Parser {
                     if self.kind_is(TokenKind::RightBrace) {
                         break;
                     }
-                    array_exp.push_element(self.parse_exp()?);
+                    array_exp.push_element(self.parse_arg_exp()?);
                     if self.kind_is(TokenKind::Comma) {
                         self.forward();
                     }
@@ -247,7 +461,10 @@ impl Parser {
                 ))
             }
 
-            TokenKind::LeftBracket => Err(self.unsupported_error("objectLiteral expression")),
+            TokenKind::LeftBracket => Ok(ASTNode::new(
+                Exp::ObjectLiteral(self.parse_object_literal()?.ctx()),
+                Span::new(begin, self.mark_end()),
+            )),
 
             // ---------------------------------------------------------------
             // parse (...)
@@ -272,6 +489,12 @@ impl Parser {
             //     Exp::FunctionExp(self.parse_func_exp_decl()?),
             //     Span::new(begin, self.mark_end()),
             // )),
+            TokenKind::KeyWord(KeyWordKind::Function) if self.nextkind_is(TokenKind::Multiply) => {
+                Ok(ASTNode::new(
+                    Exp::GeneratorExp(self.parse_generator_func_decl()?.ctx()),
+                    Span::new(begin, self.mark_end()),
+                ))
+            }
             TokenKind::KeyWord(KeyWordKind::Function) => Ok(ASTNode::new(
                 Exp::FunctionExp(self.parse_func_exp_decl()?.ctx()),
                 Span::new(begin, self.mark_end()),
@@ -287,6 +510,177 @@ impl Parser {
         }
     }
 
+    /*
+    objectLiteral: '{' (propertyAssignment (',' propertyAssignment)* ','?)? '}';
+    propertyAssignment:
+        propertyName ':' singleExpression
+        | Async? '*'? propertyName callSignature '{' functionBody '}'
+        | getAccessor
+        | setAccessor
+        | '...' singleExpression
+        | Identifier;
+    */
+    fn parse_object_literal(&mut self) -> ParseResult<ASTNode<ObjectLiteral>> {
+        let begin = self.mark_begin();
+
+        let mut object_literal = ObjectLiteral::default();
+        self.eat(TokenKind::LeftBracket)?;
+        while !self.kind_is(TokenKind::RightBracket) {
+            object_literal.push_property_assignment(self.parse_property_assignment()?);
+            if !self.kind_is(TokenKind::RightBracket) {
+                self.eat(TokenKind::Comma)?;
+            }
+        }
+        self.eat(TokenKind::RightBracket)?;
+
+        Ok(ASTNode::new(
+            object_literal,
+            Span::new(begin, self.mark_end()),
+        ))
+    }
+
+    fn parse_property_assignment(&mut self) -> ParseResult<ASTNode<PropertyAssignment>> {
+        let begin = self.mark_begin();
+
+        let property_assignment = match self.peek_kind() {
+            TokenKind::Ellipsis => {
+                self.eat(TokenKind::Ellipsis)?;
+                PropertyAssignment::SpreadProperty(SpreadProperty::new(self.parse_exp()?))
+            }
+
+            // get: 1, get() {} 中的 get 只是属性名
+            TokenKind::KeyWord(KeyWordKind::Get) | TokenKind::KeyWord(KeyWordKind::Set)
+                if self.is_property_name_start(1) =>
+            {
+                PropertyAssignment::Accesser(self.parse_accesser()?.ctx())
+            }
+
+            // async a() {}, *a() {}, async *a() {}
+            TokenKind::KeyWord(KeyWordKind::Async)
+                if self.is_property_name_start(1) || self.nextkind_is(TokenKind::Multiply) =>
+            {
+                PropertyAssignment::MethodProperty(self.parse_method_property()?)
+            }
+            TokenKind::Multiply => {
+                PropertyAssignment::MethodProperty(self.parse_method_property()?)
+            }
+
+            // { a, b }
+            _ if self.is_identifier()
+                && matches!(self.next_kind(), TokenKind::Comma | TokenKind::RightBracket) =>
+            {
+                PropertyAssignment::PropertyShorthand(PropertyShorthand::new(
+                    self.parse_identifier()?,
+                ))
+            }
+
+            _ => match self.lookahead_property_name_end() {
+                TokenKind::Colon => {
+                    let property_name = self.parse_property_name()?;
+                    self.eat(TokenKind::Colon)?;
+                    PropertyAssignment::PropertyExpAssignment(PropertyExpAssignment::new(
+                        property_name,
+                        self.parse_exp()?,
+                    ))
+                }
+                _ => PropertyAssignment::MethodProperty(self.parse_method_property()?),
+            },
+        };
+
+        Ok(ASTNode::new(
+            property_assignment,
+            Span::new(begin, self.mark_end()),
+        ))
+    }
+
+    // Async? '*'? propertyName callSignature '{' functionBody '}'
+    fn parse_method_property(&mut self) -> ParseResult<MethodProperty> {
+        let mut method_property = MethodProperty::default();
+
+        if self.kind_is(TokenKind::KeyWord(KeyWordKind::Async)) && !self.is_property_name_end(1) {
+            self.forward();
+            method_property.set_async();
+        }
+        if self.kind_is(TokenKind::Multiply) {
+            self.forward();
+            method_property.set_generator();
+        }
+        method_property.set_property_name(self.parse_property_name()?);
+        method_property.set_call_sig(self.parse_call_sig()?);
+
+        self.eat(TokenKind::LeftBracket)?;
+        method_property.set_func_body(self.parse_func_body()?);
+        self.eat(TokenKind::RightBracket)?;
+
+        Ok(method_property)
+    }
+
+    // propertyName: identifierName | StringLiteral | NumericLiteral | '[' singleExpression ']'
+    pub(super) fn parse_property_name(&mut self) -> ParseResult<ASTNode<PropertyName>> {
+        let begin = self.mark_begin();
+
+        let property_name = match self.peek_kind() {
+            TokenKind::String | TokenKind::Number | TokenKind::BigInt => {
+                PropertyName::Literal(self.extact_literal()?)
+            }
+            TokenKind::LeftBrace => {
+                self.eat(TokenKind::LeftBrace)?;
+                let exp = self.parse_exp()?;
+                self.eat(TokenKind::RightBrace)?;
+                PropertyName::ComputedPropertyName(ComputedPropertyName::new(exp))
+            }
+            _ => PropertyName::Identifier(self.parse_identifier_name()?.ctx()),
+        };
+
+        Ok(ASTNode::new(
+            property_name,
+            Span::new(begin, self.mark_end()),
+        ))
+    }
+
+    fn is_property_name_start(&self, distance: usize) -> bool {
+        let kind = self.lookahead(distance);
+        kind.is_identifier_name()
+            || matches!(
+                kind,
+                TokenKind::String | TokenKind::Number | TokenKind::BigInt | TokenKind::LeftBrace
+            )
+    }
+
+    // async: 1, async() {} 中的 async 是属性名
+    fn is_property_name_end(&self, distance: usize) -> bool {
+        matches!(
+            self.lookahead(distance),
+            TokenKind::Colon
+                | TokenKind::LeftParen
+                | TokenKind::LessThan
+                | TokenKind::Comma
+                | TokenKind::RightBracket
+        )
+    }
+
+    // 属性名之后的 token, 计算属性名 [...] 需要跳过整个括号
+    fn lookahead_property_name_end(&self) -> TokenKind {
+        if !self.kind_is(TokenKind::LeftBrace) {
+            return self.lookahead(1);
+        }
+
+        let mut depth = 0;
+        let mut distance = 0;
+        loop {
+            match self.lookahead(distance) {
+                TokenKind::LeftBrace => depth += 1,
+                TokenKind::RightBrace => depth -= 1,
+                TokenKind::EOF => return TokenKind::EOF,
+                _ => {}
+            }
+            distance += 1;
+            if depth == 0 {
+                return self.lookahead(distance);
+            }
+        }
+    }
+
     fn parse_group_exp(&mut self) -> ParseResult<ASTNode<GroupExp>> {
         let begin = self.mark_begin();
 
@@ -300,6 +694,125 @@ impl Parser {
         ))
     }
 
+    /*
+    templateLiteral:
+        NoSubstitutionTemplate
+        | TemplateHead expression (TemplateMiddle expression)* TemplateTail;
+    */
+    fn parse_template_exp(&mut self) -> ParseResult<ASTNode<TemplateExp>> {
+        let begin = self.mark_begin();
+
+        let mut template_exp = TemplateExp::default();
+        template_exp.set_head(self.extract_template_string()?);
+        if self.prekind_is(TokenKind::NoSubstitutionTemplate) {
+            return Ok(ASTNode::new(
+                template_exp,
+                Span::new(begin, self.mark_end()),
+            ));
+        }
+
+        loop {
+            let span_begin = self.mark_begin();
+            let exp = self.parse_exp()?;
+            if !self.kind_is(TokenKind::TemplateMiddle) && !self.kind_is(TokenKind::TemplateTail) {
+                return Err(self.expect_error("Template Literal", "} of ${...}"));
+            }
+            let literal = self.extract_template_string()?;
+            template_exp.push_template_span(ASTNode::new(
+                TemplateSpan::new(exp, literal),
+                Span::new(span_begin, self.mark_end()),
+            ));
+
+            if self.prekind_is(TokenKind::TemplateTail) {
+                break;
+            }
+        }
+
+        Ok(ASTNode::new(
+            template_exp,
+            Span::new(begin, self.mark_end()),
+        ))
+    }
+
+    // 注意，该函数在 extract 的同时也会 eat Token
+    pub(super) fn extract_template_string(&mut self) -> ParseResult<ASTNode<Literal>> {
+        let begin = self.mark_begin();
+        let string = Literal::String(self.peek().unwrap().peek_value().to_string());
+        self.forward();
+        Ok(ASTNode::new(string, Span::new(begin, self.mark_end())))
+    }
+
+    fn is_generic_arrow(&self) -> bool {
+        self.lookahead(1).is_identifier()
+            && matches!(
+                self.lookahead(2),
+                TokenKind::Comma | TokenKind::KeyWord(KeyWordKind::Extends)
+            )
+    }
+
+    /*
+    f<T>(x) 中的 < 可能是类型实参, 也可能是小于号
+    只有找到匹配的 > 并且之后紧跟 ( 时才是类型实参, 与 tsc 一致, a < b > (c) 也会被当作函数调用
+    */
+    fn is_call_type_args(&self) -> bool {
+        if !self.kind_is(TokenKind::LessThan) {
+            return false;
+        }
+
+        // depth 是 <> 的层数, nested 是 () [] {} 的层数
+        let mut depth = 0;
+        let mut nested = 0;
+        let mut distance = 0;
+        loop {
+            match self.lookahead(distance) {
+                TokenKind::LessThan => depth += 1,
+                TokenKind::MoreThan => depth -= 1,
+                TokenKind::RightShiftArithmetic => depth -= 2,
+                TokenKind::RightShiftLogical => depth -= 3,
+                TokenKind::LeftParen | TokenKind::LeftBrace | TokenKind::LeftBracket => nested += 1,
+                TokenKind::RightParen | TokenKind::RightBrace | TokenKind::RightBracket => {
+                    if nested == 0 {
+                        return false;
+                    }
+                    nested -= 1;
+                }
+                // 对象类型中可以出现 ;, 例如 f<{ a: T; b: U }>()
+                TokenKind::SemiColon if nested == 0 => return false,
+                // 类型中不会出现的运算符
+                TokenKind::EOF
+                | TokenKind::And
+                | TokenKind::Or
+                | TokenKind::Plus
+                | TokenKind::Multiply
+                | TokenKind::Divide
+                | TokenKind::Modulus
+                | TokenKind::Not
+                | TokenKind::PlusPlus
+                | TokenKind::MinusMinus
+                | TokenKind::Equals
+                | TokenKind::NotEquals
+                | TokenKind::IdentityEquals
+                | TokenKind::IdentityNotEquals
+                | TokenKind::LessThanEquals
+                | TokenKind::GreaterThanEquals
+                | TokenKind::Assign => return false,
+                _ => {}
+            }
+            distance += 1;
+
+            if depth < 0 {
+                return false;
+            }
+            if depth == 0 {
+                return self.lookahead(distance) == TokenKind::LeftParen;
+            }
+        }
+    }
+
+    fn is_template_begin(&self) -> bool {
+        self.kind_is(TokenKind::NoSubstitutionTemplate) || self.kind_is(TokenKind::TemplateHead)
+    }
+
     // New NamespaceName typeArguments? (' (exp (',' exp)*)? ')'
     fn parse_new_exp_decl(&mut self) -> ParseResult<ASTNode<NewExp>> {
         let begin = self.mark_begin();
@@ -318,7 +831,7 @@ impl Parser {
             new_exp.set_args(empty_args)
         } else {
             let args_exp = ASTNode::new(
-                ArgsExp::new(self.parse_exp_seq()?),
+                ArgsExp::new(self.parse_arg_seq()?),
                 Span::new(begin, self.mark_end()),
             );
             new_exp.set_args(args_exp)
@@ -373,6 +886,10 @@ impl Parser {
             TokenKind::LeftShiftArithmeticAssign => Op::LeftShiftArithmeticAssign,
             TokenKind::RightShiftArithmeticAssign => Op::RightShiftArithmeticAssign,
             TokenKind::RightShiftLogicalAssign => Op::RightShiftLogicalAssign,
+            TokenKind::PowerAssign => Op::PowerAssign,
+            TokenKind::NullCoalesceAssign => Op::NullCoalesceAssign,
+            TokenKind::AndAssign => Op::AndAssign,
+            TokenKind::OrAssign => Op::OrAssign,
 
             // ? :
             TokenKind::QuestionMark => Op::QuestionMark,
@@ -383,7 +900,9 @@ impl Parser {
             TokenKind::Multiply => Op::Multiply,
             TokenKind::Divide => Op::Divide,
             TokenKind::Modulus => Op::Mod,
+            TokenKind::Power => Op::Power,
 
+            TokenKind::NullCoalesce => Op::NullCoalesce,
             TokenKind::Or => Op::Or,
             TokenKind::And => Op::And,
             TokenKind::BitOr => Op::BitOr,
@@ -414,6 +933,19 @@ impl Parser {
         Ok(op)
     }
 
+    fn parse_ternary_exp(&mut self, cond: ASTNode<Exp>) -> ParseResult<ASTNode<Exp>> {
+        let begin = cond.info.span.get_begin();
+
+        let true_br = self.allow_in(true, Parser::parse_exp)?;
+        self.eat(TokenKind::Colon)?;
+        let false_br = self.parse_exp()?;
+
+        Ok(ASTNode::new(
+            Exp::TernaryExp(TernaryExp::new(cond, true_br, false_br)),
+            Span::new(begin, self.mark_end()),
+        ))
+    }
+
     fn extract_exp_from_stack(
         &mut self,
         mut op_stack: Vec<Op>,
@@ -464,6 +996,7 @@ impl Parser {
         if op.is_bin_op() {
             let op = op_stack.pop().unwrap();
             if let (Some(right), Some(left)) = (exp_stack.pop(), exp_stack.pop()) {
+                self.check_bin_operands(&left, op, &right)?;
                 let begin = left.info.span.get_begin();
                 let end = right.info.span.get_end();
                 let exp = Exp::BinaryExp(BinaryExp::new(left, op, right));
@@ -475,41 +1008,43 @@ impl Parser {
                 )));
             }
         }
-        // todo 处理前缀后缀
-        else if op.is_unary_op() {
+        // 前缀后缀和三元表达式都不会进入 op_stack
+        else {
             unreachable!()
-        } else if op.is_tenary_op() {
-            if op_stack.len() < 2 {
-                // 只有一个元素
-                return Err(self.report_error(&format!(
-                    "{:?} is tenary operater but another operater missing",
-                    op
-                )));
-            }
+        }
 
-            if exp_stack.len() < 3 {
+        Ok(())
+    }
+
+    fn check_bin_operands(
+        &mut self,
+        left: &ASTNode<Exp>,
+        op: Op,
+        right: &ASTNode<Exp>,
+    ) -> ParseResult<()> {
+        // -a ** b 有歧义, 必须写成 (-a) ** b 或者 -(a ** b)
+        if let (Op::Power, Exp::UnaryExp(unary)) = (op, left.ctx_ref()) {
+            if !matches!(unary.get_op(), Op::PostInc | Op::PostDec) {
                 return Err(self.report_error(&format!(
-                    "tenary operater need three expression but there is only {}",
-                    exp_stack.len()
+                    "An unary expression with the {:?} operator is not allowed in the left-hand side of an exponentiation expression, consider enclosing the expression in parentheses",
+                    unary.get_op()
                 )));
             }
+        }
 
-            let op_colon = op_stack.pop().unwrap();
-            assert_eq!(op_colon, Op::Colon);
-            let op_question = op_stack.pop().unwrap();
-            assert_eq!(op_question, Op::QuestionMark);
-
-            let false_br = exp_stack.pop().unwrap();
-            let true_br = exp_stack.pop().unwrap();
-            let cond = exp_stack.pop().unwrap();
-
-            let begin = cond.info.span.get_begin();
-            let end = false_br.info.span.get_end();
-
-            let exp = Exp::TernaryExp(TernaryExp::new(cond, true_br, false_br));
-            exp_stack.push(ASTNode::new(exp, Span::new(begin, end)));
-        } else {
-            unreachable!()
+        // ?? 不能与 || && 混用, 除非加上括号
+        let is_logical = |op: &Op| matches!(op, Op::Or | Op::And);
+        let is_mixed = [left, right].iter().any(|operand| match operand.ctx_ref() {
+            Exp::BinaryExp(operand) => match op {
+                Op::NullCoalesce => is_logical(operand.get_op()),
+                _ if is_logical(&op) => operand.get_op() == &Op::NullCoalesce,
+                _ => false,
+            },
+            _ => false,
+        });
+        if is_mixed {
+            return Err(self
+                .report_error("?? operations cannot be mixed with || or && without parentheses"));
         }
 
         Ok(())
@@ -528,24 +1063,32 @@ impl Parser {
             |TokenKind::BitXorAssign       // ^=
             |TokenKind::LeftShiftArithmeticAssign     // <<=
             |TokenKind::RightShiftArithmeticAssign  // >>=
-            |TokenKind::RightShiftLogicalAssign => true, // >>>=
+            |TokenKind::RightShiftLogicalAssign  // >>>=
+            |TokenKind::PowerAssign         // **=
+            |TokenKind::NullCoalesceAssign  // ??=
+            |TokenKind::AndAssign           // &&=
+            |TokenKind::OrAssign => true,   // ||=
             _ => false //
         }
     }
 
     fn is_single_exp_op(&mut self) -> bool {
         match self.peek_kind() {
-            // ? :
+            // for 语句头部中的 in 属于 for-in
+            TokenKind::KeyWord(KeyWordKind::In) => !self.no_in,
+
+            // ? 之后是三元表达式的两个分支
             TokenKind::QuestionMark
-            | TokenKind::Colon
             // + - *  /
             | TokenKind::Plus
             | TokenKind::Minus
             | TokenKind::Multiply
             | TokenKind::Divide
             | TokenKind::Modulus
+            | TokenKind::Power
 
-            // || && | ^ &
+            // ?? || && | ^ &
+            | TokenKind::NullCoalesce
             | TokenKind::Or
             | TokenKind::And
             | TokenKind::BitOr
@@ -562,7 +1105,6 @@ impl Parser {
             | TokenKind::MoreThan
             | TokenKind::GreaterThanEquals
 
-            | TokenKind::KeyWord(KeyWordKind::In)
             | TokenKind::KeyWord(KeyWordKind::Instanceof)
             | TokenKind::KeyWord(KeyWordKind::As)
 
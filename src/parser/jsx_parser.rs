@@ -0,0 +1,240 @@
+use crate::{
+    ast::{
+        ast_node::{exp::Exp, identifier::Identifier, jsx::*, literal::Literal},
+        ASTNode, Span,
+    },
+    lexer::token_kind::TokenKind,
+};
+
+use super::{ParseResult, Parser};
+
+impl Parser {
+    /*
+    jsxElement:
+        '<' jsxTagName jsxAttribute* '/' '>'
+        | '<' jsxTagName jsxAttribute* '>' jsxChild* '<' '/' jsxTagName '>';
+    jsxFragment:
+        '<' '>' jsxChild* '<' '/' '>';
+    */
+    pub(super) fn parse_jsx_exp(&mut self) -> ParseResult<ASTNode<Exp>> {
+        let begin = self.mark_begin();
+        let exp = match self.nextkind_is(TokenKind::MoreThan) {
+            true => Exp::JsxFragment(self.parse_jsx_fragment()?),
+            false => Exp::JsxElement(self.parse_jsx_element()?),
+        };
+        Ok(ASTNode::new(exp, Span::new(begin, self.mark_end())))
+    }
+
+    fn parse_jsx_element(&mut self) -> ParseResult<JsxElement> {
+        let mut element = JsxElement::default();
+
+        self.eat(TokenKind::LessThan)?;
+        let name_begin = self.mark_begin();
+        let name = self.extract_jsx_tag_name()?;
+        element.set_name(ASTNode::new(
+            Identifier::new(&name),
+            Span::new(name_begin, self.mark_end()),
+        ));
+
+        while !self.kind_is(TokenKind::Divide) && !self.kind_is(TokenKind::MoreThan) {
+            element.push_attribute(self.parse_jsx_attr()?);
+        }
+
+        // <div />
+        if self.kind_is(TokenKind::Divide) {
+            self.eat(TokenKind::Divide)?;
+            self.eat(TokenKind::MoreThan)?;
+            return Ok(element);
+        }
+
+        self.eat(TokenKind::MoreThan)?;
+        for child in self.parse_jsx_children()? {
+            element.push_child(child);
+        }
+
+        self.eat(TokenKind::LessThan)?;
+        self.eat(TokenKind::Divide)?;
+        let closing_name = self.extract_jsx_tag_name()?;
+        if closing_name != name {
+            return Err(self.report_error(&format!(
+                "Expected corresponding JSX closing tag for [{}] but got [{}]",
+                name, closing_name
+            )));
+        }
+        self.eat(TokenKind::MoreThan)?;
+
+        Ok(element)
+    }
+
+    fn parse_jsx_fragment(&mut self) -> ParseResult<JsxFragment> {
+        let mut fragment = JsxFragment::default();
+
+        self.eat(TokenKind::LessThan)?;
+        self.eat(TokenKind::MoreThan)?;
+        for child in self.parse_jsx_children()? {
+            fragment.push_child(child);
+        }
+        self.eat(TokenKind::LessThan)?;
+        self.eat(TokenKind::Divide)?;
+        self.eat(TokenKind::MoreThan)?;
+
+        Ok(fragment)
+    }
+
+    // jsxTagName: Identifier ('.' Identifier)* | Identifier ':' Identifier;
+    fn extract_jsx_tag_name(&mut self) -> ParseResult<String> {
+        let mut name = self.extract_jsx_name()?;
+        if self.kind_is(TokenKind::Colon) {
+            self.forward();
+            name = format!("{}:{}", name, self.extract_jsx_name()?);
+            return Ok(name);
+        }
+        while self.kind_is(TokenKind::Dot) {
+            self.forward();
+            name = format!("{}.{}", name, self.extract_jsx_name()?);
+        }
+        Ok(name)
+    }
+
+    // 注意，该函数在 extract 的同时也会 eat Token
+    fn extract_jsx_name(&mut self) -> ParseResult<String> {
+        if !self.kind_is(TokenKind::Identifier) {
+            return Err(self.expect_error("JSX Name", "Identifier"));
+        }
+        let name = self.peek().unwrap().peek_value().to_string();
+        self.forward();
+        Ok(name)
+    }
+
+    /*
+    jsxAttribute:
+        '{' '...' singleExpression '}'
+        | jsxAttributeName ('=' jsxAttributeValue)?;
+    jsxAttributeName: Identifier (':' Identifier)?;
+    jsxAttributeValue: String | '{' singleExpression '}' | jsxElement | jsxFragment;
+    */
+    fn parse_jsx_attr(&mut self) -> ParseResult<ASTNode<JsxAttr>> {
+        let begin = self.mark_begin();
+
+        if self.kind_is(TokenKind::LeftBracket) {
+            self.eat(TokenKind::LeftBracket)?;
+            self.eat(TokenKind::Ellipsis)?;
+            let exp = self.parse_exp()?;
+            self.eat(TokenKind::RightBracket)?;
+            return Ok(ASTNode::new(
+                JsxAttr::SpreadAttribute(JsxSpreadAttribute::new(exp)),
+                Span::new(begin, self.mark_end()),
+            ));
+        }
+
+        let mut attribute = JsxAttribute::default();
+        let mut name = self.extract_jsx_name()?;
+        if self.kind_is(TokenKind::Colon) {
+            self.forward();
+            name = format!("{}:{}", name, self.extract_jsx_name()?);
+        }
+        attribute.set_name(ASTNode::new(
+            Identifier::new(&name),
+            Span::new(begin, self.mark_end()),
+        ));
+
+        if self.kind_is(TokenKind::Assign) {
+            self.eat(TokenKind::Assign)?;
+
+            let value_begin = self.mark_begin();
+            let value = match self.peek_kind() {
+                TokenKind::String => {
+                    let string = Literal::String(self.peek().unwrap().peek_value().to_string());
+                    self.forward();
+                    JsxAttrValue::String(string)
+                }
+                TokenKind::LeftBracket => {
+                    let container = self.parse_jsx_exp_container()?;
+                    if container.is_empty() {
+                        return Err(self.report_error(
+                            "JSX attributes must only be assigned a non-empty expression",
+                        ));
+                    }
+                    JsxAttrValue::ExpContainer(container)
+                }
+                TokenKind::LessThan if self.nextkind_is(TokenKind::MoreThan) => {
+                    JsxAttrValue::Fragment(self.parse_jsx_fragment()?)
+                }
+                TokenKind::LessThan => JsxAttrValue::Element(self.parse_jsx_element()?),
+                _ => {
+                    return Err(self.expect_error("JSX Attribute Value", "string or { or <"));
+                }
+            };
+            attribute.set_value(ASTNode::new(value, Span::new(value_begin, self.mark_end())));
+        }
+
+        Ok(ASTNode::new(
+            JsxAttr::Attribute(attribute),
+            Span::new(begin, self.mark_end()),
+        ))
+    }
+
+    // jsxChild: JsxText | '{' singleExpression? '}' | jsxElement | jsxFragment;
+    // 遇到闭合标签的 '<' '/' 时结束
+    fn parse_jsx_children(&mut self) -> ParseResult<Vec<ASTNode<JsxChild>>> {
+        let mut children = Vec::new();
+
+        loop {
+            let begin = self.mark_begin();
+            let child = match self.peek_kind() {
+                TokenKind::LessThan if self.nextkind_is(TokenKind::Divide) => break,
+                TokenKind::LessThan if self.nextkind_is(TokenKind::MoreThan) => {
+                    JsxChild::Fragment(self.parse_jsx_fragment()?)
+                }
+                TokenKind::LessThan => JsxChild::Element(self.parse_jsx_element()?),
+                TokenKind::LeftBracket => JsxChild::ExpContainer(self.parse_jsx_exp_container()?),
+                TokenKind::JsxText => {
+                    let text = normalize_jsx_text(self.peek().unwrap().peek_value());
+                    self.forward();
+                    // 只有空白和换行的文本会被丢弃
+                    if text.is_empty() {
+                        continue;
+                    }
+                    JsxChild::Text(Literal::String(text))
+                }
+                _ => return Err(self.expect_error("JSX Child", "text or { or <")),
+            };
+            children.push(ASTNode::new(child, Span::new(begin, self.mark_end())));
+        }
+
+        Ok(children)
+    }
+
+    // '{' singleExpression? '}', 只有注释时表达式为空
+    fn parse_jsx_exp_container(&mut self) -> ParseResult<JsxExpContainer> {
+        let mut container = JsxExpContainer::default();
+
+        self.eat(TokenKind::LeftBracket)?;
+        if !self.kind_is(TokenKind::RightBracket) {
+            container.set_exp(self.parse_exp()?);
+        }
+        self.eat(TokenKind::RightBracket)?;
+
+        Ok(container)
+    }
+}
+
+// 与 React 一致: 去掉换行两侧的空白, 丢弃空行, 剩下的行用一个空格连接
+fn normalize_jsx_text(text: &str) -> String {
+    let lines: Vec<&str> = text.split('\n').collect();
+    let last = lines.len() - 1;
+    lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| {
+            let line = if i > 0 { line.trim_start() } else { line };
+            if i < last {
+                line.trim_end()
+            } else {
+                line
+            }
+        })
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
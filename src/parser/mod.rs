@@ -1,6 +1,8 @@
 pub mod error;
 mod exp_parser;
+mod jsx_parser;
 mod parser_util;
+mod pattern_parser;
 
 use crate::ast::ast_node::decorator::Decorators;
 use crate::ast::ast_node::exp;
@@ -8,7 +10,7 @@ use crate::ast::ast_node::identifier;
 use crate::ast::ast_node::identifier::Identifier;
 use crate::ast::ast_node::literal::Literal;
 use crate::ast::ast_node::program::Program;
-use crate::ast::Span;
+use crate::ast::{Pos, Span};
 
 use crate::ast::visulize::Visualizable;
 use crate::ast::ASTNode;
@@ -43,6 +45,12 @@ pub(crate) struct Parser {
 
     error_most_possible: Option<ParserError>,
     try_most_forward: usize,
+
+    // 是否在解析 .tsx 文件, 此时表达式开头的 < 是 JSX 标签
+    jsx: bool,
+
+    // for 语句头部中 in 不能作为二元运算符, 否则 for (x in obj) 中的 x in obj 会被当成一个表达式
+    no_in: bool,
 }
 impl Parser {
     pub(crate) fn new(tokens: Vec<Token>) -> Self {
@@ -51,9 +59,16 @@ impl Parser {
             index: 0,
             error_most_possible: None,
             try_most_forward: 0,
+            jsx: false,
+            no_in: false,
         }
     }
 
+    pub(crate) fn set_jsx(mut self) -> Self {
+        self.jsx = true;
+        self
+    }
+
     // general error report
     fn report_error(&mut self, msg: &str) -> ParserError {
         let cur = self.peek().unwrap();
@@ -63,15 +78,19 @@ impl Parser {
             return self.error_most_possible.clone().unwrap();
         }
 
-        ParserError::new(format!("near Line[{}]:\n{}", cur.peek_line(), msg))
+        let pos = cur.peek_begin();
+        ParserError::new(format!(
+            "near Line[{}], Column[{}]:\n{}",
+            pos.line, pos.column, msg
+        ))
     }
 
-    fn mark_begin(&self) -> usize {
-        self.peek().unwrap().peek_line()
+    fn mark_begin(&self) -> Pos {
+        self.peek().unwrap().peek_begin()
     }
 
-    fn mark_end(&self) -> usize {
-        self.prepeek().peek_line()
+    fn mark_end(&self) -> Pos {
+        self.prepeek().peek_end()
     }
 
     /*
@@ -110,6 +129,18 @@ impl Parser {
         }
     }
 
+    // 在 allow 指定的 in 状态下执行 func, 结束之后恢复原来的状态
+    fn allow_in<T>(
+        &mut self,
+        allow: bool,
+        func: fn(&mut Parser) -> ParseResult<T>,
+    ) -> ParseResult<T> {
+        let no_in = std::mem::replace(&mut self.no_in, !allow);
+        let result = func(self);
+        self.no_in = no_in;
+        result
+    }
+
     pub(crate) fn parse(&mut self) -> Result<AST, TSError> {
         Ok(AST::new(self.parse_program()?, Compiler::filename()))
     }
@@ -149,6 +180,7 @@ impl Parser {
         let begin = self.mark_begin();
 
         let stat = match self.peek_kind() {
+            // 语句开头的 { 总是代码块, 对象字面量作为语句时需要用括号包起来, 例如 ({ a } = b);
             TokenKind::LeftBracket => Stat::Block(self.parse_block()?.ctx()),
 
             TokenKind::KeyWord(KeyWordKind::Import) => Stat::ImportStat(self.parse_import_stat()?),
@@ -166,21 +198,25 @@ impl Parser {
             TokenKind::SemiColon => Stat::EmptyStat(self.parse_empty_stat()?),
 
             // abstract class or abstract ?
-            TokenKind::KeyWord(KeyWordKind::Abstract) => match self.next_kind() {
-                TokenKind::KeyWord(KeyWordKind::Class) => {
-                    Stat::ClassDecl(self.parse_class_decl()?.ctx())
-                }
+            TokenKind::KeyWord(KeyWordKind::Abstract) if self.is_contextual_decl() => {
+                match self.next_kind() {
+                    TokenKind::KeyWord(KeyWordKind::Class) => {
+                        Stat::ClassDecl(self.parse_class_decl()?.ctx())
+                    }
 
-                TokenKind::Identifier => Stat::AbsDecl(self.parse_abstract_decl()?.ctx()),
+                    kind if kind.is_identifier() => {
+                        Stat::AbsDecl(self.parse_abstract_decl()?.ctx())
+                    }
 
-                _ => {
-                    self.forward();
-                    return Err(self.expect_error(
-                        "The abstract keyword can only modify class or identifier",
-                        "class or identifier",
-                    ));
+                    _ => {
+                        self.forward();
+                        return Err(self.expect_error(
+                            "The abstract keyword can only modify class or identifier",
+                            "class or identifier",
+                        ));
+                    }
                 }
-            },
+            }
 
             TokenKind::KeyWord(KeyWordKind::Class) => {
                 Stat::ClassDecl(self.parse_class_decl()?.ctx())
@@ -190,7 +226,7 @@ impl Parser {
                 Stat::InterfaceDecl(self.parse_interface_decl()?.ctx())
             }
 
-            TokenKind::KeyWord(KeyWordKind::Namespace) => {
+            TokenKind::KeyWord(KeyWordKind::Namespace) if self.is_contextual_decl() => {
                 Stat::NamespaceDecl(self.parse_namespace_decl()?.ctx())
             }
 
@@ -209,8 +245,6 @@ impl Parser {
 
             TokenKind::KeyWord(KeyWordKind::Return) => Stat::ReturnStat(self.parse_return_stat()?),
 
-            TokenKind::KeyWord(KeyWordKind::Yield) => Stat::YieldStat(self.parse_yield_stat()?),
-
             TokenKind::KeyWord(KeyWordKind::With) => Stat::WithStat(self.parse_with_stat()?),
 
             TokenKind::KeyWord(KeyWordKind::Switch) => Stat::SwitchStat(self.parse_switch_stat()?),
@@ -223,38 +257,16 @@ impl Parser {
                 Stat::DebuggerStat(self.parse_debugger_stat()?)
             }
 
-            // function 需要进一步往前探索
-            TokenKind::KeyWord(KeyWordKind::Function) => match self.next_kind() {
-                TokenKind::LeftParen => Stat::FuncExpDecl(self.parse_func_exp_decl()?.ctx()),
-
-                TokenKind::Identifier => {
-                    // func a<T>()
-                    if self.lookahead(2) == TokenKind::LessThan {
-                        Stat::FuncDecl(self.parse_func_decl()?.ctx())
-                    } else {
-                        // 至此，只有尝试了
-                        if let Some(func_exp) = self.try_to(Parser::parse_func_exp_decl) {
-                            Stat::FuncExpDecl(func_exp.ctx())
-                        } else if let Some(func_decl) = self.try_to(Parser::parse_func_decl) {
-                            Stat::FuncDecl(func_decl.ctx())
-                        } else {
-                            return Err(self.expect_error(
-                                "illegal function declartion",
-                                "function declaration or function expression",
-                            ));
-                        }
-                    }
-                }
-
-                TokenKind::Multiply => Stat::GenFuncDecl(self.parse_generator_func_decl()?.ctx()),
+            // async function 或者 async function*
+            TokenKind::KeyWord(KeyWordKind::Async)
+                if self.is_async_prefix()
+                    && self.nextkind_is(TokenKind::KeyWord(KeyWordKind::Function)) =>
+            {
+                self.parse_func_stat()?
+            }
 
-                _ => {
-                    self.forward();
-                    return Err(
-                        self.expect_error("illegal function declaretion", "Identifier, ( or *")
-                    );
-                }
-            },
+            // function 需要进一步往前探索
+            TokenKind::KeyWord(KeyWordKind::Function) => self.parse_func_stat()?,
 
             TokenKind::KeyWord(KeyWordKind::Enum) => Stat::EnumStat(self.parse_enum_stat()?),
 
@@ -269,19 +281,23 @@ impl Parser {
                 }
             }
 
-            TokenKind::KeyWord(KeyWordKind::Declare)
-            | TokenKind::KeyWord(KeyWordKind::Public)
+            TokenKind::KeyWord(KeyWordKind::Public)
             | TokenKind::KeyWord(KeyWordKind::Protected)
             | TokenKind::KeyWord(KeyWordKind::Private)
             | TokenKind::KeyWord(KeyWordKind::Var)
-            | TokenKind::KeyWord(KeyWordKind::Let)
-            | TokenKind::KeyWord(KeyWordKind::ReadOnly) => Stat::VarStat(self.parse_var_stat()?.ctx()),
+            | TokenKind::KeyWord(KeyWordKind::Let) => Stat::VarStat(self.parse_var_stat()?.ctx()),
 
-            TokenKind::KeyWord(KeyWordKind::Type) => {
+            TokenKind::KeyWord(KeyWordKind::Declare)
+            | TokenKind::KeyWord(KeyWordKind::ReadOnly) if self.is_contextual_decl() => {
+                Stat::VarStat(self.parse_var_stat()?.ctx())
+            }
+
+            TokenKind::KeyWord(KeyWordKind::Type) if self.is_contextual_decl() => {
                 Stat::TypeAliasStat(self.parse_typealias_stat()?)
             }
 
-            TokenKind::Identifier => match self.next_kind() {
+            // 标识符, 或者作为标识符使用的上下文关键字
+            _ if self.is_identifier() => match self.next_kind() {
                 TokenKind::Colon => Stat::LabelledStat(self.parse_labelled_stat()?),
 
                 _ => {
@@ -304,9 +320,14 @@ impl Parser {
             | TokenKind::KeyWord(KeyWordKind::New)
             | TokenKind::KeyWord(KeyWordKind::Delete)
             | TokenKind::KeyWord(KeyWordKind::Typeof)
+            | TokenKind::KeyWord(KeyWordKind::Yield)
             // literal
             | TokenKind::String
             | TokenKind::Number
+            | TokenKind::BigInt
+            | TokenKind::RegExp
+            | TokenKind::NoSubstitutionTemplate
+            | TokenKind::TemplateHead
             | TokenKind::KeyWord(KeyWordKind::True)
             | TokenKind::KeyWord(KeyWordKind::False)
             | TokenKind::KeyWord(KeyWordKind::Null)
@@ -362,7 +383,7 @@ impl Parser {
 
         self.eat(TokenKind::KeyWord(KeyWordKind::Import))?;
 
-        if self.kind_is(TokenKind::Identifier) && self.nextkind_is(TokenKind::Assign) {
+        if self.is_identifier() && self.nextkind_is(TokenKind::Assign) {
             let import_stat = ImportStat::new(ASTNode::new(
                 ImportBlock::ImportAssign(self.set_import_alias_decl()?),
                 Span::new(begin, self.mark_end()),
@@ -447,7 +468,7 @@ impl Parser {
                 if self.kind_is(TokenKind::KeyWord(KeyWordKind::As)) {
                     self.forward();
                     match self.peek_kind() {
-                        TokenKind::Identifier => {
+                        _ if self.is_identifier() => {
                             from_block.set_all_alias(self.parse_identifier()?);
                             if self.kind_is(TokenKind::Comma) {
                                 self.forward();
@@ -459,8 +480,8 @@ impl Parser {
                     }
                 }
             }
-            TokenKind::Identifier | TokenKind::LeftBracket => {
-                if self.kind_is(TokenKind::Identifier) {
+            _ if self.is_identifier() || self.kind_is(TokenKind::LeftBracket) => {
+                if self.is_identifier() {
                     from_block.set_imported(self.parse_identifier()?);
 
                     if self.kind_is(TokenKind::Comma) {
@@ -471,7 +492,7 @@ impl Parser {
                 if self.kind_is(TokenKind::LeftBracket) {
                     self.eat(TokenKind::LeftBracket)?;
 
-                    while self.kind_is(TokenKind::Identifier) {
+                    while self.is_identifier() {
                         let import_alias_begin = self.mark_begin();
                         let imported = self.parse_identifier()?;
 
@@ -570,8 +591,6 @@ impl Parser {
     /*
     classDeclaration:
         Abstract? Class Identifier typeParameters? classHeritage classTail;
-
-    for now typeParameters is not supported
     */
     fn parse_class_decl(&mut self) -> ParseResult<ASTNode<ClassDecl>> {
         let begin = self.mark_begin();
@@ -583,7 +602,7 @@ impl Parser {
         }
         self.eat(TokenKind::KeyWord(KeyWordKind::Class))?;
         match self.peek_kind() {
-            TokenKind::Identifier => {
+            _ if self.is_identifier() => {
                 class_decl.set_class_name(self.parse_identifier()?);
             }
             _ => return Err(self.expect_error("ClassDecl Stat", "Identifer(class name)")),
@@ -648,7 +667,7 @@ impl Parser {
         let mut type_ref;
 
         match self.peek_kind() {
-            TokenKind::Identifier => {
+            _ if self.is_identifier() => {
                 if self.nextkind_is(TokenKind::Dot) {
                     type_ref = TypeRef::new_namespace(self.parse_namespace_name()?);
                 } else {
@@ -668,7 +687,23 @@ impl Parser {
     typeArgumentList: typeArgument (',' typeArgument)*;
     */
     fn parse_type_generic(&mut self) -> ParseResult<ASTNode<TypeGeneric>> {
-        Err(self.unsupported_error("Type Generic"))
+        let begin = self.mark_begin();
+
+        let mut type_generic = TypeGeneric::default();
+        self.eat(TokenKind::LessThan)?;
+        loop {
+            type_generic.push_type_arg(self.parse_type_arg()?);
+            if !self.kind_is(TokenKind::Comma) {
+                break;
+            }
+            self.forward();
+        }
+        self.eat_more_than()?;
+
+        Ok(ASTNode::new(
+            type_generic,
+            Span::new(begin, self.mark_end()),
+        ))
     }
 
     /*
@@ -697,7 +732,8 @@ impl Parser {
     classElement:
         constructorDeclaration
         | propertyMemberDeclaration
-        | indexMemberDeclaration;
+        | indexMemberDeclaration
+        | Static '{' functionBody '}';
 
     constructorDeclaration 第一个是访问修饰符, 第二个必定是 constructor 关键字
     indexMemberDeclaration  以 [ 开头
@@ -712,12 +748,6 @@ impl Parser {
                 Span::new(begin, self.mark_end()),
             )),
 
-            // propertyMemberDeclaration
-            TokenKind::Identifier => Ok(ASTNode::new(
-                ClassElement::PropertyMemberDecl(self.parse_property_member_decl()?),
-                Span::new(begin, self.mark_end()),
-            )),
-
             // propertyMemberDeclaration -> getter_setter_decl_exp
             TokenKind::KeyWord(KeyWordKind::Get) | TokenKind::KeyWord(KeyWordKind::Set) => {
                 Ok(ASTNode::new(
@@ -732,8 +762,17 @@ impl Parser {
                 Span::new(begin, self.mark_end()),
             )),
 
-            // propertyMemberDeclaration
-            TokenKind::KeyWord(KeyWordKind::Async)
+            // static { ... }
+            TokenKind::KeyWord(KeyWordKind::Static) if self.nextkind_is(TokenKind::LeftBracket) => {
+                Ok(ASTNode::new(
+                    ClassElement::StaticBlock(self.parse_static_block()?),
+                    Span::new(begin, self.mark_end()),
+                ))
+            }
+
+            // propertyMemberDeclaration, * 开头的是生成器方法
+            TokenKind::Multiply
+            | TokenKind::KeyWord(KeyWordKind::Async)
             | TokenKind::KeyWord(KeyWordKind::Static)
             | TokenKind::KeyWord(KeyWordKind::ReadOnly)
             | TokenKind::KeyWord(KeyWordKind::Abstract) => Ok(ASTNode::new(
@@ -757,6 +796,14 @@ impl Parser {
                 )),
             },
 
+            // propertyMemberDeclaration, 成员名可以是任意关键字或者私有名, 例如 delete() #x
+            _ if self.is_identifier_name() || self.kind_is(TokenKind::PrivateIdentifier) => {
+                Ok(ASTNode::new(
+                    ClassElement::PropertyMemberDecl(self.parse_property_member_decl()?),
+                    Span::new(begin, self.mark_end()),
+                ))
+            }
+
             _ => Err(self.expect_error(
                 "Class Element",
                 "constructorDeclaration or propertyMemberDeclaration or indexMemberDeclaration",
@@ -764,6 +811,19 @@ impl Parser {
         }
     }
 
+    /*
+    Static '{' functionBody '}'
+    */
+    fn parse_static_block(&mut self) -> ParseResult<StaticBlock> {
+        let mut static_block = StaticBlock::default();
+
+        self.eat(TokenKind::KeyWord(KeyWordKind::Static))?;
+        self.eat(TokenKind::LeftBracket)?;
+        static_block.set_func_body(self.parse_func_body()?);
+        self.eat(TokenKind::RightBracket)?;
+        Ok(static_block)
+    }
+
     /*
     constructorDeclaration:
         accessibilityModifier? Constructor '(' formalParameterList? ')' '{' functionBody '}';
@@ -851,16 +911,16 @@ impl Parser {
             property_decl_exp.set_access_modifier(access_modifier);
         }
 
-        if self.kind_is(TokenKind::KeyWord(KeyWordKind::Static)) {
+        if self.kind_is(TokenKind::KeyWord(KeyWordKind::Static)) && self.is_member_modifier() {
             self.eat(TokenKind::KeyWord(KeyWordKind::Static))?;
             property_decl_exp.set_static();
         }
-        if self.kind_is(TokenKind::KeyWord(KeyWordKind::ReadOnly)) {
+        if self.kind_is(TokenKind::KeyWord(KeyWordKind::ReadOnly)) && self.is_member_modifier() {
             self.eat(TokenKind::KeyWord(KeyWordKind::ReadOnly))?;
             property_decl_exp.set_readonly();
         }
 
-        property_decl_exp.set_identifier(self.parse_identifier()?);
+        property_decl_exp.set_identifier(self.parse_member_name()?);
 
         if self.kind_is(TokenKind::QuestionMark) {
             property_decl_exp.set_question_mark();
@@ -876,13 +936,13 @@ impl Parser {
             property_decl_exp.set_initializer(self.parse_exp()?);
         }
 
-        self.eat(TokenKind::SemiColon)?;
+        self.eat_eos()?;
 
         Ok(property_decl_exp)
     }
 
     /*
-    accessibilityModifier? Static? Async? Identifier callSignature ( ('{' functionBody '}') | SemiColon )
+    accessibilityModifier? Static? Async? '*'? Identifier callSignature ( ('{' functionBody '}') | SemiColon )
         */
     fn parse_method_decl_exp(&mut self) -> ParseResult<MethodDeclExp> {
         let begin = self.mark_begin();
@@ -893,16 +953,24 @@ impl Parser {
             method_decl_exp.set_access_modifier(access_modifier);
         }
 
-        if self.kind_is(TokenKind::KeyWord(KeyWordKind::Static)) {
+        if self.kind_is(TokenKind::KeyWord(KeyWordKind::Static)) && self.is_member_modifier() {
             self.forward();
             method_decl_exp.set_static();
         }
-        if self.kind_is(TokenKind::KeyWord(KeyWordKind::Async)) {
+        // async 与成员名之间不能换行, 否则 async 是一个字段, 例如 async \n m() {}
+        if self.kind_is(TokenKind::KeyWord(KeyWordKind::Async))
+            && self.is_member_modifier()
+            && self.is_next_on_same_line()
+        {
             self.forward();
             method_decl_exp.set_async();
         }
+        if self.kind_is(TokenKind::Multiply) {
+            self.forward();
+            method_decl_exp.set_generator();
+        }
 
-        method_decl_exp.set_identifier(self.parse_identifier()?);
+        method_decl_exp.set_identifier(self.parse_member_name()?);
         method_decl_exp.set_call_sig(self.parse_call_sig()?);
 
         match self.peek_kind() {
@@ -933,13 +1001,17 @@ impl Parser {
         if let Some(access_modifier) = self.try_to(Parser::parse_access_modifier) {
             access_modifier_ = Some(access_modifier);
         }
-        if self.kind_is(TokenKind::KeyWord(KeyWordKind::Static)) {
+        if self.kind_is(TokenKind::KeyWord(KeyWordKind::Static)) && self.is_member_modifier() {
             static_ = true;
             self.forward();
         }
 
         let getter_setter_decl_exp =
             GetterSetterDeclExp::new(access_modifier_, static_, self.parse_accesser()?);
+        // 没有函数体时 ; 已经被吃掉了
+        if !self.prekind_is(TokenKind::SemiColon) {
+            self.eat_eos()?;
+        }
         Ok(getter_setter_decl_exp)
     }
 
@@ -949,7 +1021,7 @@ impl Parser {
             TokenKind::KeyWord(KeyWordKind::Get) => {
                 let mut accesser = GetAccesser::default();
                 self.forward();
-                accesser.set_identifier(self.parse_identifier()?);
+                accesser.set_identifier(self.parse_member_name()?);
                 self.eat(TokenKind::LeftParen)?;
                 self.eat(TokenKind::RightParen)?;
                 if self.kind_is(TokenKind::Colon) {
@@ -962,14 +1034,13 @@ impl Parser {
                     self.eat(TokenKind::LeftBracket)?;
                     accesser.set_func_body(self.parse_func_body()?);
                     self.eat(TokenKind::RightBracket)?;
-                    self.eat_eos()?;
                     Accesser::GetAccessor(accesser)
                 }
             }
             TokenKind::KeyWord(KeyWordKind::Set) => {
                 let mut accesser = SetAccesser::default();
                 self.forward();
-                accesser.set_identifier(self.parse_identifier()?);
+                accesser.set_identifier(self.parse_member_name()?);
 
                 self.eat(TokenKind::LeftParen)?;
                 accesser.set_parameter(self.parse_identifier()?);
@@ -987,7 +1058,6 @@ impl Parser {
                     self.eat(TokenKind::LeftBracket)?;
                     accesser.set_func_body(self.parse_func_body()?);
                     self.eat(TokenKind::RightBracket)?;
-                    self.eat_eos()?;
                     Accesser::SetAccessor(accesser)
                 }
             }
@@ -1066,7 +1136,7 @@ impl Parser {
         self.eat(TokenKind::KeyWord(KeyWordKind::Abstract))?;
 
         let begin1 = self.mark_begin();
-        if self.kind_is(TokenKind::Identifier) && self.nextkind_is(TokenKind::LeftParen) {
+        if self.is_identifier_name() && self.nextkind_is(TokenKind::LeftParen) {
             let identifier = self.parse_identifier()?;
             let call_sig = self.parse_call_sig()?;
             abs_method = AbsMember::AbsMethod(AbsMethod::new(identifier, call_sig));
@@ -1117,7 +1187,11 @@ impl Parser {
             statement # ForStatement
         | For '(' varModifier variableDeclarationList SemiColon singleExpression? SemiColon
             expressionSequence? ')' statement							# ForVarStatement
-        | For '(' singleExpression In singleExpression ')' statement	# ForInStatement;
+        | For '(' forTarget In singleExpression ')' statement	# ForInStatement
+        | For '(' forTarget Of singleExpression ')' statement	# ForOfStatement
+        | For Await '(' forTarget Of singleExpression ')' statement	# ForAwaitStatement;
+
+        forTarget: varModifier (identifier | bindingPattern) | bindingPattern | singleExpression;
 
         varModifier:Var | Let | Const;
 
@@ -1155,6 +1229,13 @@ impl Parser {
                 Ok(IterStat::WhileStat(while_stat))
             }
 
+            // for await (... of ...)
+            TokenKind::KeyWord(KeyWordKind::For)
+                if self.nextkind_is(TokenKind::KeyWord(KeyWordKind::Await)) =>
+            {
+                Ok(IterStat::ForAwaitStat(self.parse_forawait_stat()?))
+            }
+
             TokenKind::KeyWord(KeyWordKind::For) => {
                 if let Some(for_stat) = self.try_to(Parser::parse_for_stat) {
                     return Ok(IterStat::ForStat(for_stat));
@@ -1164,13 +1245,17 @@ impl Parser {
                     return Ok(IterStat::ForInStat(forin_stat));
                 }
 
+                if let Some(forof_stat) = self.try_to(Parser::parse_forof_stat) {
+                    return Ok(IterStat::ForOfStat(forof_stat));
+                }
+
                 if let Some(forvar_stat) = self.try_to(Parser::parse_forvar_stat) {
                     return Ok(IterStat::ForVarStat(forvar_stat));
                 }
 
                 Err(self.expect_error(
                     "For Statement",
-                    "for statement or forin statement or forvar statement",
+                    "for statement or forin statement or forof statement or forvar statement",
                 ))
             }
 
@@ -1188,7 +1273,7 @@ impl Parser {
         self.eat(TokenKind::KeyWord(KeyWordKind::For))?;
         self.eat(TokenKind::LeftParen)?;
         if !self.kind_is(TokenKind::SemiColon) {
-            for_stat.set_init(self.parse_exp_seq()?);
+            for_stat.set_init(self.allow_in(false, Parser::parse_exp_seq)?);
         }
         self.eat(TokenKind::SemiColon)?;
         if !self.kind_is(TokenKind::SemiColon) {
@@ -1204,30 +1289,107 @@ impl Parser {
     }
 
     /*
-    For '(' identifier In expression ')' statement	# ForInStatement;
+    For '(' forTarget In expression ')' statement	# ForInStatement;
     */
     fn parse_forin_stat(&mut self) -> ParseResult<ASTNode<ForInStat>> {
         let begin = self.mark_begin();
-        let var;
-        let exp;
-        let stat;
         self.eat(TokenKind::KeyWord(KeyWordKind::For))?;
         self.eat(TokenKind::LeftParen)?;
 
-        let ident_begin = self.mark_begin();
-        let identifier = Exp::Identifier(self.parse_identifier()?.ctx());
-        var = ASTNode::new(identifier, Span::new(ident_begin, self.mark_end()));
-
+        let (var_modifier, var) = self.parse_for_target()?;
         self.eat(TokenKind::KeyWord(KeyWordKind::In))?;
-        exp = self.parse_exp()?;
+        let exp = self.parse_exp()?;
         self.eat(TokenKind::RightParen)?;
-        stat = self.parse_stat()?;
+        let stat = self.parse_stat()?;
+
+        let mut forin_stat = ForInStat::new(var, exp, stat);
+        if let Some(var_modifier) = var_modifier {
+            forin_stat.set_var_modifier(var_modifier);
+        }
+        Ok(ASTNode::new(forin_stat, Span::new(begin, self.mark_end())))
+    }
+
+    /*
+    For '(' forTarget Of expression ')' statement	# ForOfStatement;
+    */
+    fn parse_forof_stat(&mut self) -> ParseResult<ASTNode<ForOfStat>> {
+        let begin = self.mark_begin();
+        self.eat(TokenKind::KeyWord(KeyWordKind::For))?;
+        self.eat(TokenKind::LeftParen)?;
+
+        let (var_modifier, var) = self.parse_for_target()?;
+        self.eat(TokenKind::KeyWord(KeyWordKind::Of))?;
+        let exp = self.parse_exp()?;
+        self.eat(TokenKind::RightParen)?;
+        let stat = self.parse_stat()?;
+
+        let mut forof_stat = ForOfStat::new(var, exp, stat);
+        if let Some(var_modifier) = var_modifier {
+            forof_stat.set_var_modifier(var_modifier);
+        }
+        Ok(ASTNode::new(forof_stat, Span::new(begin, self.mark_end())))
+    }
+
+    /*
+    For Await '(' forTarget Of expression ')' statement	# ForAwaitStatement;
+    */
+    fn parse_forawait_stat(&mut self) -> ParseResult<ASTNode<ForAwaitStat>> {
+        let begin = self.mark_begin();
+        self.eat(TokenKind::KeyWord(KeyWordKind::For))?;
+        self.eat(TokenKind::KeyWord(KeyWordKind::Await))?;
+        self.eat(TokenKind::LeftParen)?;
+
+        let (var_modifier, var) = self.parse_for_target()?;
+        self.eat(TokenKind::KeyWord(KeyWordKind::Of))?;
+        let exp = self.parse_exp()?;
+        self.eat(TokenKind::RightParen)?;
+        let stat = self.parse_stat()?;
+
+        let mut forawait_stat = ForAwaitStat::new(var, exp, stat);
+        if let Some(var_modifier) = var_modifier {
+            forawait_stat.set_var_modifier(var_modifier);
+        }
         Ok(ASTNode::new(
-            ForInStat::new(var, exp, stat),
+            forawait_stat,
             Span::new(begin, self.mark_end()),
         ))
     }
 
+    /*
+    for-in 和 for-of 中 in/of 左边的部分
+    forTarget: varModifier (identifier | bindingPattern) | bindingPattern | singleExpression;
+    */
+    fn parse_for_target(&mut self) -> ParseResult<(Option<ASTNode<VarModifier>>, ASTNode<Exp>)> {
+        let mut var_modifier = None;
+        if matches!(
+            self.peek_kind(),
+            TokenKind::KeyWord(KeyWordKind::Var)
+                | TokenKind::KeyWord(KeyWordKind::Let)
+                | TokenKind::KeyWord(KeyWordKind::Const)
+        ) {
+            var_modifier = Some(self.parse_var_modifier()?);
+        }
+
+        let var_begin = self.mark_begin();
+        let var = match self.peek_kind() {
+            // for (const [key, value] of map)
+            TokenKind::LeftBrace | TokenKind::LeftBracket => ASTNode::new(
+                Exp::BindingPattern(self.parse_assign_pattern()?.ctx()),
+                Span::new(var_begin, self.mark_end()),
+            ),
+
+            _ if var_modifier.is_some() => ASTNode::new(
+                Exp::Identifier(self.parse_identifier()?.ctx()),
+                Span::new(var_begin, self.mark_end()),
+            ),
+
+            // for (obj.key in source), 此处的 in 不能被当成二元运算符
+            _ => self.allow_in(false, Parser::parse_single_exp)?,
+        };
+
+        Ok((var_modifier, var))
+    }
+
     /*
     For '(' varModifier variableDeclarationList SemiColon expression? SemiColon expressionSequence? ')' statement							# ForVarStatement
     )?;
@@ -1245,7 +1407,7 @@ impl Parser {
         self.eat(TokenKind::LeftParen)?;
 
         var_modifier = self.parse_var_modifier()?;
-        var_decl_list = self.parse_var_decl_list()?;
+        var_decl_list = self.allow_in(false, Parser::parse_var_decl_list)?;
         self.eat(TokenKind::SemiColon)?;
 
         if !self.kind_is(TokenKind::SemiColon) {
@@ -1288,14 +1450,17 @@ impl Parser {
     }
 
     /*
-    variableDeclaration: (Identifier | arrayLiteral | objectLiteral) typeAnnotation? ('=' expression)?;
+    variableDeclaration: (Identifier | bindingPattern) typeAnnotation? ('=' expression)?;
     */
     fn parse_var_decl(&mut self) -> ParseResult<ASTNode<VarDecl>> {
         let begin = self.mark_begin();
 
         match self.peek_kind() {
-            TokenKind::Identifier => {
-                let mut var_decl = VarDecl::new(self.parse_identifier()?);
+            _ if self.is_identifier()
+                || self.kind_is(TokenKind::LeftBrace)
+                || self.kind_is(TokenKind::LeftBracket) =>
+            {
+                let mut var_decl = VarDecl::new(self.parse_binding_target()?);
                 if self.kind_is(TokenKind::Colon) {
                     var_decl.set_type_annotation(self.parse_type_annotation()?);
                 }
@@ -1305,9 +1470,7 @@ impl Parser {
                 }
                 Ok(ASTNode::new(var_decl, Span::new(begin, self.mark_end())))
             }
-            TokenKind::LeftBrace => Err(self.unsupported_error("Array Matching")),
-            TokenKind::LeftBracket => Err(self.unsupported_error("Object Matching")),
-            _ => Err(self.expect_error("Var Decl", "Identifier or arrayLiteral or objectLiteral")),
+            _ => Err(self.expect_error("Var Decl", "Identifier or bindingPattern")),
         }
     }
 
@@ -1318,7 +1481,7 @@ impl Parser {
     fn parse_continue_stat(&mut self) -> ParseResult<ContinueStat> {
         let mut continue_stat = ContinueStat::default();
         self.eat(TokenKind::KeyWord(KeyWordKind::Continue))?;
-        if self.kind_is(TokenKind::Identifier) {
+        if self.is_identifier() {
             continue_stat.set_identifier(self.parse_identifier()?);
         }
         self.eat_eos()?;
@@ -1333,7 +1496,7 @@ impl Parser {
         let mut break_stat = BreakStat::default();
         self.eat(TokenKind::KeyWord(KeyWordKind::Break))?;
 
-        if self.kind_is(TokenKind::Identifier) {
+        if self.is_identifier() {
             break_stat.set_identifier(self.parse_identifier()?);
         }
         self.eat_eos()?;
@@ -1353,17 +1516,6 @@ impl Parser {
         Ok(return_stat)
     }
 
-    /*
-    yieldStatement: Yield (expressionSequence)? eos;
-    */
-    fn parse_yield_stat(&mut self) -> ParseResult<YieldStat> {
-        let mut yield_stat = YieldStat::default();
-        self.eat(TokenKind::KeyWord(KeyWordKind::Yield))?;
-        yield_stat.set_exp_seq(self.parse_exp_seq()?);
-        self.eat_eos()?;
-        Ok(yield_stat)
-    }
-
     //    : With '(' expressionSequence ')' statement
     fn parse_with_stat(&mut self) -> ParseResult<WithStat> {
         self.eat(TokenKind::KeyWord(KeyWordKind::With))?;
@@ -1376,10 +1528,9 @@ impl Parser {
 
     // Identifier ':' statement
     fn parse_labelled_stat(&mut self) -> ParseResult<LabelledStat> {
-        let identifier = &self.extact_identifier()?;
+        let identifier = self.parse_identifier()?;
         self.eat(TokenKind::Colon)?;
         let stat = self.parse_stat()?;
-        let identifier = self.parse_identifier()?;
         Ok(LabelledStat::new(identifier, stat))
     }
 
@@ -1474,9 +1625,15 @@ impl Parser {
     // Throw {this.notLineTerminator()}? expressionSequence eos
     fn parse_throw_stat(&mut self) -> ParseResult<ThrowStat> {
         self.eat(TokenKind::KeyWord(KeyWordKind::Throw))?;
-        let exp_seq = self.parse_exp_seq()?;
+        if self.is_new_line() {
+            return Err(self.report_error(&format!(
+                "line break is not allowed after throw in Line[{}]",
+                self.prepeek().peek_line()
+            )));
+        }
+        let throw_stat = ThrowStat::new(self.parse_exp_seq()?);
         self.eat_eos()?;
-        todo!()
+        Ok(throw_stat)
     }
 
     // Try block (catchProduction finallyProduction? | finallyProduction)
@@ -1484,10 +1641,96 @@ impl Parser {
         let mut try_stat = TryStat::default();
         self.eat(TokenKind::KeyWord(KeyWordKind::Try))?;
         try_stat.set_block(self.parse_block()?);
+
+        if !self.kind_is(TokenKind::KeyWord(KeyWordKind::Catch))
+            && !self.kind_is(TokenKind::KeyWord(KeyWordKind::Finally))
+        {
+            return Err(self.expect_error("Try Stat", "catch or finally"));
+        }
         if self.kind_is(TokenKind::KeyWord(KeyWordKind::Catch)) {
-            todo!()
+            try_stat.set_catch_clause(self.parse_catch_clause()?);
+        }
+        if self.kind_is(TokenKind::KeyWord(KeyWordKind::Finally)) {
+            try_stat.set_finally_clause(self.parse_finally_clause()?);
+        }
+        Ok(try_stat)
+    }
+
+    /*
+    catchProduction: Catch ('(' (Identifier | bindingPattern) typeAnnotation? ')')? block;
+    */
+    fn parse_catch_clause(&mut self) -> ParseResult<ASTNode<CatchClause>> {
+        let begin = self.mark_begin();
+
+        let mut catch_clause = CatchClause::default();
+        self.eat(TokenKind::KeyWord(KeyWordKind::Catch))?;
+        if self.kind_is(TokenKind::LeftParen) {
+            self.forward();
+            catch_clause.set_catch_para(self.parse_binding_target()?);
+            if self.kind_is(TokenKind::Colon) {
+                catch_clause.set_type_annotation(self.parse_type_annotation()?);
+            }
+            self.eat(TokenKind::RightParen)?;
         }
-        todo!()
+        catch_clause.set_block(self.parse_block()?);
+
+        Ok(ASTNode::new(
+            catch_clause,
+            Span::new(begin, self.mark_end()),
+        ))
+    }
+
+    // finallyProduction: Finally block;
+    fn parse_finally_clause(&mut self) -> ParseResult<ASTNode<FinallyClause>> {
+        let begin = self.mark_begin();
+
+        self.eat(TokenKind::KeyWord(KeyWordKind::Finally))?;
+        let finally_clause = FinallyClause::new(self.parse_block()?);
+
+        Ok(ASTNode::new(
+            finally_clause,
+            Span::new(begin, self.mark_end()),
+        ))
+    }
+
+    // function 声明或函数表达式语句, 开头可能有 async 修饰
+    fn parse_func_stat(&mut self) -> ParseResult<Stat> {
+        // async 占一个 token, 之后的探索整体往后挪一位
+        let offset = usize::from(self.kind_is(TokenKind::KeyWord(KeyWordKind::Async)));
+
+        let stat = match self.lookahead(offset + 1) {
+            TokenKind::LeftParen => Stat::FuncExpDecl(self.parse_func_exp_decl()?.ctx()),
+
+            kind if kind.is_identifier() => {
+                // func a<T>()
+                if self.lookahead(offset + 2) == TokenKind::LessThan {
+                    Stat::FuncDecl(self.parse_func_decl()?.ctx())
+                } else {
+                    // 至此，只有尝试了
+                    if let Some(func_exp) = self.try_to(Parser::parse_func_exp_decl) {
+                        Stat::FuncExpDecl(func_exp.ctx())
+                    } else if let Some(func_decl) = self.try_to(Parser::parse_func_decl) {
+                        Stat::FuncDecl(func_decl.ctx())
+                    } else {
+                        return Err(self.expect_error(
+                            "illegal function declartion",
+                            "function declaration or function expression",
+                        ));
+                    }
+                }
+            }
+
+            TokenKind::Multiply => Stat::GenFuncDecl(self.parse_generator_func_decl()?.ctx()),
+
+            _ => {
+                for _ in 0..=offset {
+                    self.forward();
+                }
+                return Err(self.expect_error("illegal function declaretion", "Identifier, ( or *"));
+            }
+        };
+
+        Ok(stat)
     }
 
     fn parse_debugger_stat(&mut self) -> ParseResult<DebuggerStat> {
@@ -1496,12 +1739,16 @@ impl Parser {
 
     /*
     functionDeclaration
-        : Function Identifier callSignature ( ('{' functionBody '}') | SemiColon);
+        : Async? Function Identifier callSignature ( ('{' functionBody '}') | SemiColon);
     */
     fn parse_func_decl(&mut self) -> ParseResult<ASTNode<FuncDecl>> {
         let begin = self.mark_begin();
 
         let mut func_body = None;
+        let async_ = self.kind_is(TokenKind::KeyWord(KeyWordKind::Async));
+        if async_ {
+            self.forward();
+        }
         self.eat(TokenKind::KeyWord(KeyWordKind::Function))?;
         let func_name = self.parse_identifier()?;
         let call_sig = self.parse_call_sig()?;
@@ -1513,20 +1760,30 @@ impl Parser {
             self.eat(TokenKind::SemiColon)?;
         }
 
-        let func_decl = FuncDecl::new(func_name, call_sig, func_body);
+        let mut func_decl = FuncDecl::new(func_name, call_sig, func_body);
+        if async_ {
+            func_decl.set_async();
+        }
         Ok(ASTNode::new(func_decl, Span::new(begin, self.mark_end())))
     }
 
     // functionExpressionDeclaration:
-    // Function_ Identifier? '(' formalParameterList? ')' typeAnnotation? '{' functionBody '}';
+    // Async? Function_ Identifier? typeParameters? '(' formalParameterList? ')' typeAnnotation? '{' functionBody '}';
     fn parse_func_exp_decl(&mut self) -> ParseResult<ASTNode<FuncExpDecl>> {
         let begin = self.mark_begin();
 
         let mut func_exp_decl = FuncExpDecl::default();
+        if self.kind_is(TokenKind::KeyWord(KeyWordKind::Async)) {
+            self.forward();
+            func_exp_decl.set_async();
+        }
         self.eat(TokenKind::KeyWord(KeyWordKind::Function))?;
-        if self.kind_is(TokenKind::Identifier) {
+        if self.is_identifier() {
             func_exp_decl.set_func_name(self.parse_identifier()?);
         }
+        if self.kind_is(TokenKind::LessThan) {
+            func_exp_decl.set_type_paras(self.parse_type_paras()?);
+        }
         self.eat(TokenKind::LeftParen)?;
         if !self.kind_is(TokenKind::RightParen) {
             func_exp_decl.set_formal_paras(self.parse_formal_parameters()?);
@@ -1555,10 +1812,16 @@ impl Parser {
         let begin = self.mark_begin();
 
         let mut arrow_func = ArrowFuncExpDecl::default();
-        if self.kind_is(TokenKind::KeyWord(KeyWordKind::Async)) {
+        // async => ... 中的 async 只是参数名
+        if self.kind_is(TokenKind::KeyWord(KeyWordKind::Async))
+            && !self.nextkind_is(TokenKind::Arrow)
+        {
             self.forward();
             arrow_func.set_async();
         }
+        if self.kind_is(TokenKind::LessThan) {
+            arrow_func.set_type_paras(self.parse_type_paras()?);
+        }
 
         let para_begin = self.mark_begin();
         match self.peek_kind() {
@@ -1574,9 +1837,9 @@ impl Parser {
                 }
                 self.eat(TokenKind::RightParen)?;
             }
-            TokenKind::Identifier => {
+            _ if self.is_identifier() => {
                 let mut formal_para = FormalPara::default();
-                formal_para.set_identifier(self.parse_identifier()?);
+                formal_para.set_identifier(self.parse_binding_target()?);
 
                 let mut formal_paras = FormalParas::default();
                 formal_paras
@@ -1631,10 +1894,10 @@ impl Parser {
 
         let paras_begin = self.mark_begin();
         match self.peek_kind() {
-            TokenKind::Ellipsis
-            | TokenKind::Identifier
-            | TokenKind::LeftBracket
-            | TokenKind::LeftBrace => call_sig.set_para_list(self.parse_para_list()?),
+            TokenKind::Ellipsis | TokenKind::LeftBracket | TokenKind::LeftBrace => {
+                call_sig.set_para_list(self.parse_para_list()?)
+            }
+            _ if self.is_identifier() => call_sig.set_para_list(self.parse_para_list()?),
 
             // 即使 () 内什么也没有，也要去申请结点。
             // 因为内容和结点是分离的
@@ -1689,12 +1952,14 @@ impl Parser {
         let begin = self.mark_begin();
 
         let mut property_sig = PropertySig::default();
-        if self.kind_is(TokenKind::KeyWord(KeyWordKind::ReadOnly)) {
+        if self.kind_is(TokenKind::KeyWord(KeyWordKind::ReadOnly))
+            && self.next_kind().is_identifier_name()
+        {
             property_sig.set_readonly();
             self.forward();
         }
 
-        property_sig.set_property_name(self.parse_identifier()?);
+        property_sig.set_property_name(self.parse_identifier_name()?);
 
         if self.kind_is(TokenKind::QuestionMark) {
             property_sig.set_question_mark();
@@ -1718,7 +1983,7 @@ impl Parser {
         let begin = self.mark_begin();
 
         let mut method_sig = MethodSig::default();
-        method_sig.set_method_name(self.parse_identifier()?);
+        method_sig.set_method_name(self.parse_identifier_name()?);
         if self.kind_is(TokenKind::QuestionMark) {
             method_sig.set_question_mark();
             self.forward();
@@ -1737,43 +2002,59 @@ impl Parser {
     */
     fn parse_func_body(&mut self) -> ParseResult<ASTNode<FuncBody>> {
         // prekind is {
-        let begin = self.prepeek().peek_line();
+        let begin = self.prepeek().peek_begin();
 
         let mut func_body = FuncBody::default();
 
         if self.kind_is(TokenKind::RightBracket) {
-            let end = self.peek().unwrap().peek_line();
+            let end = self.peek().unwrap().peek_end();
             return Ok(ASTNode::new(func_body, Span::new(begin, end)));
         }
         func_body.set_func_body(self.parse_source_elements()?);
 
         // current kind is }
-        let end = self.peek().unwrap().peek_line();
+        let end = self.peek().unwrap().peek_end();
         Ok(ASTNode::new(func_body, Span::new(begin, end)))
     }
 
     /*
     生成器函数声明
-    Function_ '*' Identifier? '(' formalParameterList? ')' '{' functionBody '}'
+    Async? Function_ '*' Identifier? typeParameters? '(' formalParameterList? ')' typeAnnotation? '{' functionBody '}'
      */
     fn parse_generator_func_decl(&mut self) -> ParseResult<ASTNode<GenFuncDecl>> {
         let begin = self.mark_begin();
 
+        let mut gen_func_decl = GenFuncDecl::default();
+        if self.kind_is(TokenKind::KeyWord(KeyWordKind::Async)) {
+            self.forward();
+            gen_func_decl.set_async();
+        }
         self.eat(TokenKind::KeyWord(KeyWordKind::Function))?;
         self.eat(TokenKind::Multiply)?;
-        if self.kind_is(TokenKind::Identifier) {
-            todo!()
+        if self.is_identifier() {
+            gen_func_decl.set_func_name(self.parse_identifier()?);
+        }
+        if self.kind_is(TokenKind::LessThan) {
+            gen_func_decl.set_type_paras(self.parse_type_paras()?);
         }
         self.eat(TokenKind::LeftParen)?;
         if !self.kind_is(TokenKind::RightParen) {
-            let formal_parameters = self.parse_formal_parameters()?;
+            gen_func_decl.set_formal_paras(self.parse_formal_parameters()?);
         }
         self.eat(TokenKind::RightParen)?;
 
+        if self.kind_is(TokenKind::Colon) {
+            gen_func_decl.set_type_annotation(self.parse_type_annotation()?);
+        }
+
         self.eat(TokenKind::LeftBracket)?;
-        let func_body = self.parse_func_body()?;
+        gen_func_decl.set_func_body(self.parse_func_body()?);
         self.eat(TokenKind::RightBracket)?;
-        todo!()
+
+        Ok(ASTNode::new(
+            gen_func_decl,
+            Span::new(begin, self.mark_end()),
+        ))
     }
 
     /*
@@ -1787,8 +2068,7 @@ impl Parser {
         let mut formal_paras = FormalParas::default();
 
         if self.kind_is(TokenKind::Ellipsis) {
-            self.forward();
-            formal_paras.set_last_para_arg(self.parse_identifier()?);
+            formal_paras.set_last_para_arg(self.parse_rest_para()?);
         } else {
             loop {
                 let formal_parameter_arg = self.parse_formal_parameter_arg()?;
@@ -1797,8 +2077,7 @@ impl Parser {
                     TokenKind::Comma => {
                         self.forward();
                         if self.kind_is(TokenKind::Ellipsis) {
-                            self.forward();
-                            formal_paras.set_last_para_arg(self.parse_identifier()?);
+                            formal_paras.set_last_para_arg(self.parse_rest_para()?);
                             break;
                         }
                     }
@@ -1815,7 +2094,7 @@ impl Parser {
 
     /*
     formalParameterArg:
-        decorator? accessibilityModifier? Identifier '?'? typeAnnotation?;
+        decorator? accessibilityModifier? (Identifier | bindingPattern) '?'? typeAnnotation? initializer?;
     */
     fn parse_formal_parameter_arg(&mut self) -> ParseResult<ASTNode<FormalPara>> {
         let begin = self.mark_begin();
@@ -1830,7 +2109,7 @@ impl Parser {
             formal_para.set_access_modifier(access_modifier);
         }
 
-        formal_para.set_identifier(self.parse_identifier()?);
+        formal_para.set_identifier(self.parse_binding_target()?);
 
         if self.kind_is(TokenKind::QuestionMark) {
             formal_para.set_question_mark();
@@ -1841,6 +2120,10 @@ impl Parser {
             formal_para.set_type_annotation(self.parse_type_annotation()?);
         }
 
+        if self.kind_is(TokenKind::Assign) {
+            formal_para.set_initializer(self.parse_initializer()?);
+        }
+
         Ok(ASTNode::new(formal_para, Span::new(begin, self.mark_end())))
     }
 
@@ -1860,10 +2143,50 @@ impl Parser {
 
     /*
     typeParameters: '<' typeParameterList? '>';
-    typeParameterList: typeParameter (',' typeParameter)*;
+    typeParameterList: typeParameter (',' typeParameter)* ','?;
+    typeParameter: Identifier constraint? ('=' type_)?;
+    constraint: 'extends' type_;
     */
     fn parse_type_paras(&mut self) -> ParseResult<ASTNode<TypeParas>> {
-        Err(self.unsupported_error("type parameters"))
+        let begin = self.mark_begin();
+
+        let mut type_paras = TypeParas::default();
+        self.eat(TokenKind::LessThan)?;
+        while !self.kind_is(TokenKind::MoreThan) {
+            type_paras.push_type_para(self.parse_type_para()?);
+            if !self.kind_is(TokenKind::MoreThan) {
+                self.eat(TokenKind::Comma)?;
+            }
+        }
+        self.eat_more_than()?;
+
+        Ok(ASTNode::new(type_paras, Span::new(begin, self.mark_end())))
+    }
+
+    fn parse_type_para(&mut self) -> ParseResult<ASTNode<TypePara>> {
+        let begin = self.mark_begin();
+
+        let mut type_para = TypePara::new(self.parse_identifier()?);
+        if self.kind_is(TokenKind::KeyWord(KeyWordKind::Extends)) {
+            let constraint_begin = self.mark_begin();
+            self.forward();
+            let constraint = Constraint::new(self.parse_type()?);
+            type_para.set_constraint(ASTNode::new(
+                constraint,
+                Span::new(constraint_begin, self.mark_end()),
+            ));
+        }
+        if self.kind_is(TokenKind::Assign) {
+            let default_begin = self.mark_begin();
+            self.forward();
+            let default_type = DefaultType::new(self.parse_type()?);
+            type_para.set_default_type(ASTNode::new(
+                default_type,
+                Span::new(default_begin, self.mark_end()),
+            ));
+        }
+
+        Ok(ASTNode::new(type_para, Span::new(begin, self.mark_end())))
     }
 
     /*
@@ -1871,7 +2194,27 @@ impl Parser {
     typeArgumentList: typeArgument (',' typeArgument)*;
     */
     fn parse_type_args(&mut self) -> ParseResult<ASTNode<TypeArgs>> {
-        Err(self.unsupported_error("type arguments"))
+        let begin = self.mark_begin();
+
+        let mut type_args = TypeArgs::default();
+        self.eat(TokenKind::LessThan)?;
+        loop {
+            type_args.push_type_arg(self.parse_type_arg()?);
+            if !self.kind_is(TokenKind::Comma) {
+                break;
+            }
+            self.forward();
+        }
+        self.eat_more_than()?;
+
+        Ok(ASTNode::new(type_args, Span::new(begin, self.mark_end())))
+    }
+
+    // typeArgument: type_;
+    fn parse_type_arg(&mut self) -> ParseResult<ASTNode<TypeArg>> {
+        let begin = self.mark_begin();
+        let type_arg = TypeArg::new(self.parse_type()?);
+        Ok(ASTNode::new(type_arg, Span::new(begin, self.mark_end())))
     }
 
     /*
@@ -1890,7 +2233,10 @@ impl Parser {
                 para_list.set_rest_para(rest_para);
                 Ok(ASTNode::new(para_list, Span::new(begin, self.mark_end())))
             }
-            TokenKind::Identifier | TokenKind::LeftBracket | TokenKind::LeftBrace => {
+            _ if self.is_identifier()
+                || self.kind_is(TokenKind::LeftBracket)
+                || self.kind_is(TokenKind::LeftBrace) =>
+            {
                 loop {
                     let para = self.parse_para()?;
                     para_list.push_para(para);
@@ -1900,6 +2246,10 @@ impl Parser {
                     }
 
                     self.forward();
+                    // 剩余参数只能在最后
+                    if self.kind_is(TokenKind::Ellipsis) {
+                        break;
+                    }
                 }
 
                 if self.peek_kind() == TokenKind::Ellipsis {
@@ -1925,13 +2275,22 @@ impl Parser {
         ))
     }
 
+    // restParameter: '...' (Identifier | bindingPattern) typeAnnotation?;
     fn parse_rest_para(&mut self) -> ParseResult<ASTNode<RestPara>> {
-        Err(self.unsupported_error("rest parameter"))
+        let begin = self.mark_begin();
+
+        self.eat(TokenKind::Ellipsis)?;
+        let mut rest_para = RestPara::new(self.parse_binding_target()?);
+        if self.kind_is(TokenKind::Colon) {
+            rest_para.set_type_annotation(self.parse_type_annotation()?);
+        }
+
+        Ok(ASTNode::new(rest_para, Span::new(begin, self.mark_end())))
     }
 
     /*
     parameter:
-        decoratorList? accessibilityModifier? Identifier (
+        decoratorList? accessibilityModifier? (Identifier | bindingPattern) (
                 '?' typeAnnotation?
                 | typeAnnotation? initializer?
             )?
@@ -1940,10 +2299,6 @@ impl Parser {
     fn parse_para(&mut self) -> ParseResult<ASTNode<Para>> {
         let begin = self.mark_begin();
 
-        if self.kind_is(TokenKind::LeftBrace) || self.kind_is(TokenKind::LeftBracket) {
-            return Err(self.unsupported_error("bindingPattern"));
-        }
-
         let mut para = Para::default();
         if self.kind_is(TokenKind::At) {
             para.set_decorators(self.parse_decorators()?);
@@ -1958,7 +2313,7 @@ impl Parser {
             _ => (),
         }
 
-        para.set_para_name(self.parse_identifier()?);
+        para.set_para_name(self.parse_binding_target()?);
 
         if self.kind_is(TokenKind::QuestionMark) {
             para.set_question_mark();
@@ -1986,33 +2341,207 @@ impl Parser {
         Ok(ASTNode::new(initializer, Span::new(begin, self.mark_end())))
     }
 
+    /*
+    type_: nonConditionalType ('extends' nonConditionalType '?' type_ ':' type_)?;
+    */
     fn parse_type(&mut self) -> ParseResult<ASTNode<Type>> {
         let begin = self.mark_begin();
 
-        if self.kind_is(TokenKind::LeftParen) {
+        let check_type = self.parse_non_conditional_type()?;
+        if !self.kind_is(TokenKind::KeyWord(KeyWordKind::Extends)) {
+            return Ok(check_type);
+        }
+
+        // 条件类型 T extends U ? X : Y, extends 之后不能直接再嵌套条件类型
+        self.forward();
+        let extends_type = self.parse_non_conditional_type()?;
+        self.eat(TokenKind::QuestionMark)?;
+        let true_type = self.parse_type()?;
+        self.eat(TokenKind::Colon)?;
+        let false_type = self.parse_type()?;
+
+        Ok(ASTNode::new(
+            Type::ConditionalType(ConditionalType::new(
+                check_type,
+                extends_type,
+                true_type,
+                false_type,
+            )),
+            Span::new(begin, self.mark_end()),
+        ))
+    }
+
+    /*
+    nonConditionalType: functionType | unionType;
+    */
+    fn parse_non_conditional_type(&mut self) -> ParseResult<ASTNode<Type>> {
+        let begin = self.mark_begin();
+
+        // < 开头的只能是泛型函数类型 <T>(x: T) => T
+        if self.kind_is(TokenKind::LessThan)
+            || (self.kind_is(TokenKind::LeftParen) && self.is_func_type())
+        {
             Ok(ASTNode::new(
                 Type::FunctionType(self.parse_func_type()?),
                 Span::new(begin, self.mark_end()),
             ))
         } else {
-            Ok(ASTNode::new(
+            self.parse_union_type()
+        }
+    }
+
+    // ( 开头的可能是函数类型 (a: T) => U, 也可能是括号类型 (A | B)[], 需要跳过整个括号看后面是不是 =>
+    fn is_func_type(&self) -> bool {
+        let mut depth = 0;
+        let mut distance = 0;
+        loop {
+            match self.lookahead(distance) {
+                TokenKind::LeftParen | TokenKind::LeftBrace | TokenKind::LeftBracket => depth += 1,
+                TokenKind::RightParen | TokenKind::RightBrace | TokenKind::RightBracket => {
+                    depth -= 1
+                }
+                TokenKind::EOF => return false,
+                _ => {}
+            }
+            distance += 1;
+            if depth == 0 {
+                return self.lookahead(distance) == TokenKind::Arrow;
+            }
+        }
+    }
+
+    /*
+    unionType: '|'? intersectionType ('|' intersectionType)*;
+    */
+    fn parse_union_type(&mut self) -> ParseResult<ASTNode<Type>> {
+        let begin = self.mark_begin();
+
+        // 开头允许多写一个 |, 方便多行书写
+        if self.kind_is(TokenKind::BitOr) {
+            self.forward();
+        }
+        let type_ = self.parse_intersection_type()?;
+        if !self.kind_is(TokenKind::BitOr) {
+            return Ok(type_);
+        }
+
+        let mut union_type = UnionType::default();
+        union_type.push_type(type_);
+        while self.kind_is(TokenKind::BitOr) {
+            self.forward();
+            union_type.push_type(self.parse_intersection_type()?);
+        }
+
+        Ok(ASTNode::new(
+            Type::UnionType(union_type),
+            Span::new(begin, self.mark_end()),
+        ))
+    }
+
+    /*
+    intersectionType: '&'? primaryType ('&' primaryType)*;
+    */
+    fn parse_intersection_type(&mut self) -> ParseResult<ASTNode<Type>> {
+        let begin = self.mark_begin();
+
+        if self.kind_is(TokenKind::BitAnd) {
+            self.forward();
+        }
+        let type_ = ASTNode::new(
+            Type::PrimaryType(self.parse_primary_type()?),
+            Span::new(begin, self.mark_end()),
+        );
+        if !self.kind_is(TokenKind::BitAnd) {
+            return Ok(type_);
+        }
+
+        let mut intersection_type = IntersectionType::default();
+        intersection_type.push_type(type_);
+        while self.kind_is(TokenKind::BitAnd) {
+            self.forward();
+            let type_begin = self.mark_begin();
+            intersection_type.push_type(ASTNode::new(
                 Type::PrimaryType(self.parse_primary_type()?),
-                Span::new(begin, self.mark_end()),
-            ))
+                Span::new(type_begin, self.mark_end()),
+            ));
         }
+
+        Ok(ASTNode::new(
+            Type::IntersectionType(intersection_type),
+            Span::new(begin, self.mark_end()),
+        ))
     }
 
     /*
     primaryType:
-        predefinedType ('[' ']')?					# PredefinedPrimType
-        | typeReference	('[' ']')?			       	# ReferencePrimType
-        | '[' tupleElementTypes ']'					# TuplePrimType
-        | typeQuery                                 #QueryPrimType
-        | objectType								# ObjectPrimType;
+        'keyof' primaryType                         # KeyofPrimType
+        | 'readonly' primaryType                    # ReadonlyPrimType
+        | primaryType {notLineTerminator}? '[' ']'  # ArrayPrimType
+        | primaryType {notLineTerminator}? '[' type_ ']'    # IndexedAccessPrimType
+        | baseType                                  # BasePrimType;
     */
     fn parse_primary_type(&mut self) -> ParseResult<PrimaryType> {
         let begin = self.mark_begin();
 
+        match self.peek_kind() {
+            TokenKind::KeyWord(KeyWordKind::Keyof) => {
+                self.forward();
+                let type_begin = self.mark_begin();
+                let type_ = self.parse_primary_type()?;
+                let type_ = ASTNode::new(type_, Span::new(type_begin, self.mark_end()));
+                return Ok(PrimaryType::KeyofType(KeyofType::new(type_)));
+            }
+            TokenKind::KeyWord(KeyWordKind::ReadOnly) => {
+                self.forward();
+                let type_begin = self.mark_begin();
+                let type_ = self.parse_primary_type()?;
+                let type_ = ASTNode::new(type_, Span::new(type_begin, self.mark_end()));
+                return Ok(PrimaryType::ReadonlyType(ReadonlyType::new(type_)));
+            }
+            _ => (),
+        }
+
+        let mut primary_type = self.parse_base_type()?;
+
+        // 换行之后的 [ 不再属于该类型, 例如 type A = B 换行后 [1, 2].forEach(...)
+        while self.kind_is(TokenKind::LeftBrace) && !self.is_new_line() {
+            let element_type = ASTNode::new(primary_type, Span::new(begin, self.mark_end()));
+            self.forward();
+            if self.kind_is(TokenKind::RightBrace) {
+                self.forward();
+                primary_type = PrimaryType::ArrayType(ArrayType::new(element_type));
+            } else {
+                let index_type = self.parse_type()?;
+                self.eat(TokenKind::RightBrace)?;
+                primary_type = PrimaryType::IndexedAccessType(IndexedAccessType::new(
+                    element_type,
+                    index_type,
+                ));
+            }
+        }
+
+        Ok(primary_type)
+    }
+
+    /*
+    baseType:
+        '(' type_ ')'                               # ParenthesizedPrimType
+        | predefinedType                            # PredefinedPrimType
+        | literalType                               # LiteralPrimType
+        | typeReference                             # ReferencePrimType
+        | '[' tupleElementTypes ']'                 # TuplePrimType
+        | typeQuery                                 # QueryPrimType
+        | objectType                                # ObjectPrimType;
+    */
+    fn parse_base_type(&mut self) -> ParseResult<PrimaryType> {
+        // (
+        if self.kind_is(TokenKind::LeftParen) {
+            self.forward();
+            let parenthesized_type = ParenthesizedType::new(self.parse_type()?);
+            self.eat(TokenKind::RightParen)?;
+            return Ok(PrimaryType::ParenthesizedType(parenthesized_type));
+        }
+
         // [
         if self.kind_is(TokenKind::LeftBrace) {
             self.eat(TokenKind::LeftBrace)?;
@@ -2023,9 +2552,31 @@ impl Parser {
 
         // {
         if self.kind_is(TokenKind::LeftBracket) {
+            if self.is_mapped_type() {
+                return Ok(PrimaryType::MappedType(self.parse_mapped_type()?));
+            }
             return Ok(PrimaryType::ObjectType(self.parse_object_type()?));
         }
 
+        // `
+        if matches!(
+            self.peek_kind(),
+            TokenKind::NoSubstitutionTemplate | TokenKind::TemplateHead
+        ) {
+            return Ok(PrimaryType::TemplateLiteralType(
+                self.parse_template_literal_type()?,
+            ));
+        }
+
+        // infer R
+        if self.kind_is(TokenKind::KeyWord(KeyWordKind::Infer)) && self.next_kind().is_identifier()
+        {
+            self.forward();
+            return Ok(PrimaryType::InferType(InferType::new(
+                self.parse_identifier()?,
+            )));
+        }
+
         /*
             typeQuery
                 : 'typeof' typeQueryExpression
@@ -2049,28 +2600,26 @@ impl Parser {
             return Ok(PrimaryType::TypeQuery(type_query));
         }
 
-        if self.kind_is(TokenKind::Identifier) {
-            let type_ref = if self.nextkind_is(TokenKind::Dot) {
-                TypeRef::new_namespace(self.parse_namespace_name()?)
-            } else {
-                TypeRef::new_identifier(self.parse_identifier()?)
+        // 字面量类型, 负数需要带上前面的 -
+        if self.is_literal() {
+            return Ok(PrimaryType::LiteralType(self.extact_literal()?));
+        }
+        if self.kind_is(TokenKind::Minus)
+            && matches!(self.next_kind(), TokenKind::Number | TokenKind::BigInt)
+        {
+            self.forward();
+            let literal = match self.extact_literal()? {
+                Literal::Integer(integer) => Literal::Integer(-integer),
+                Literal::Number(number) => Literal::Number(-number),
+                Literal::BigInt(bigint) => Literal::BigInt(format!("-{}", bigint)),
+                _ => unreachable!(),
             };
+            return Ok(PrimaryType::LiteralType(literal));
+        }
 
-            if self.kind_is(TokenKind::LeftBrace) {
-                self.eat(TokenKind::LeftBrace)?;
-                self.eat(TokenKind::RightBrace)?;
-
-                if self.kind_is(TokenKind::LeftBrace) {
-                    return Err(self.unsupported_error("multi-dimensional arrays"));
-                }
-
-                let type_ref = ASTNode::new(type_ref, Span::new(begin, self.mark_end()));
-                let array_type_ref = ArrayTypeRef::new(type_ref);
-
-                return Ok(PrimaryType::ArrayTypeRef(array_type_ref));
-            } else {
-                return Ok(PrimaryType::TypeRef(type_ref));
-            }
+        // number string 等预定义类型也是上下文关键字, 需要排除
+        if self.is_identifier() && !self.is_predefined_type() {
+            return Ok(PrimaryType::TypeRef(self.parse_type_ref()?.ctx()));
         }
 
         let type_ = match self.peek_kind() {
@@ -2080,6 +2629,11 @@ impl Parser {
             TokenKind::KeyWord(KeyWordKind::String) => PredefinedType::String,
             TokenKind::KeyWord(KeyWordKind::Symbol) => PredefinedType::Symbol,
             TokenKind::KeyWord(KeyWordKind::Void) => PredefinedType::Void,
+            TokenKind::KeyWord(KeyWordKind::Unknown) => PredefinedType::Unknown,
+            TokenKind::KeyWord(KeyWordKind::Never) => PredefinedType::Never,
+            TokenKind::KeyWord(KeyWordKind::Undefined) => PredefinedType::Undefined,
+            TokenKind::KeyWord(KeyWordKind::Object) => PredefinedType::Object,
+            TokenKind::KeyWord(KeyWordKind::BigInt) => PredefinedType::BigInt,
             _ => {
                 return Err(self.expect_error(
                     "Parse Primary Type",
@@ -2089,26 +2643,161 @@ impl Parser {
         };
         self.forward();
 
-        if self.kind_is(TokenKind::LeftBrace) {
-            self.eat(TokenKind::LeftBrace)?;
-            self.eat(TokenKind::RightBrace)?;
+        Ok(PrimaryType::PredefinedType(type_))
+    }
 
-            if self.kind_is(TokenKind::LeftBrace) {
-                return Err(self.unsupported_error("multi-dimensional array"));
-            }
+    fn is_predefined_type(&self) -> bool {
+        matches!(
+            self.peek_kind(),
+            TokenKind::KeyWord(KeyWordKind::Any)
+                | TokenKind::KeyWord(KeyWordKind::Number)
+                | TokenKind::KeyWord(KeyWordKind::Boolean)
+                | TokenKind::KeyWord(KeyWordKind::String)
+                | TokenKind::KeyWord(KeyWordKind::Symbol)
+                | TokenKind::KeyWord(KeyWordKind::Void)
+                | TokenKind::KeyWord(KeyWordKind::Unknown)
+                | TokenKind::KeyWord(KeyWordKind::Never)
+                | TokenKind::KeyWord(KeyWordKind::Undefined)
+                | TokenKind::KeyWord(KeyWordKind::Object)
+                | TokenKind::KeyWord(KeyWordKind::BigInt)
+        )
+    }
+
+    // { readonly [K in ...  或者 { +readonly [K in ...  或者 { [K in ...
+    fn is_mapped_type(&self) -> bool {
+        let mut distance = 1;
+        if matches!(self.lookahead(distance), TokenKind::Plus | TokenKind::Minus) {
+            distance += 1;
+        }
+        if self.lookahead(distance) == TokenKind::KeyWord(KeyWordKind::ReadOnly) {
+            distance += 1;
+        }
+        self.lookahead(distance) == TokenKind::LeftBrace
+            && self.lookahead(distance + 1).is_identifier()
+            && self.lookahead(distance + 2) == TokenKind::KeyWord(KeyWordKind::In)
+    }
 
-            let type_ = ASTNode::new(type_, Span::new(begin, self.mark_end()));
+    /*
+    mappedType:
+        '{' ('+' | '-')? 'readonly'? '[' Identifier 'in' type_ ('as' type_)? ']'
+            ('+' | '-')? '?'? typeAnnotation? SemiColon? '}';
+    */
+    fn parse_mapped_type(&mut self) -> ParseResult<MappedType> {
+        self.eat(TokenKind::LeftBracket)?;
 
-            Ok(PrimaryType::ArrayPredefinedType(ArrayPredefinedType::new(
-                type_,
-            )))
-        } else {
-            Ok(PrimaryType::PredefinedType(type_))
+        let readonly_modifier = match self.peek_kind() {
+            TokenKind::Plus => Some(MappedModifier::AddReadonly),
+            TokenKind::Minus => Some(MappedModifier::RemoveReadonly),
+            TokenKind::KeyWord(KeyWordKind::ReadOnly) => Some(MappedModifier::Readonly),
+            _ => None,
+        };
+        if matches!(self.peek_kind(), TokenKind::Plus | TokenKind::Minus) {
+            self.forward();
+        }
+        if readonly_modifier.is_some() {
+            self.eat(TokenKind::KeyWord(KeyWordKind::ReadOnly))?;
+        }
+
+        self.eat(TokenKind::LeftBrace)?;
+        let type_para = self.parse_identifier()?;
+        self.eat(TokenKind::KeyWord(KeyWordKind::In))?;
+        let mut mapped_type = MappedType::new(type_para, self.parse_type()?);
+        if let Some(readonly_modifier) = readonly_modifier {
+            mapped_type.set_readonly_modifier(readonly_modifier);
+        }
+        if self.kind_is(TokenKind::KeyWord(KeyWordKind::As)) {
+            self.forward();
+            mapped_type.set_name_type(self.parse_type()?);
         }
+        self.eat(TokenKind::RightBrace)?;
+
+        let optional_modifier = match self.peek_kind() {
+            TokenKind::Plus => Some(MappedModifier::AddOptional),
+            TokenKind::Minus => Some(MappedModifier::RemoveOptional),
+            TokenKind::QuestionMark => Some(MappedModifier::Optional),
+            _ => None,
+        };
+        if matches!(self.peek_kind(), TokenKind::Plus | TokenKind::Minus) {
+            self.forward();
+        }
+        if let Some(optional_modifier) = optional_modifier {
+            self.eat(TokenKind::QuestionMark)?;
+            mapped_type.set_optional_modifier(optional_modifier);
+        }
+
+        if self.kind_is(TokenKind::Colon) {
+            mapped_type.set_type_annotation(self.parse_type_annotation()?);
+        }
+        if self.kind_is(TokenKind::SemiColon) {
+            self.forward();
+        }
+        self.eat(TokenKind::RightBracket)?;
+
+        Ok(mapped_type)
+    }
+
+    /*
+    templateLiteralType:
+        NoSubstitutionTemplate
+        | TemplateHead type_ (TemplateMiddle type_)* TemplateTail;
+    */
+    fn parse_template_literal_type(&mut self) -> ParseResult<TemplateLiteralType> {
+        let mut template_type = TemplateLiteralType::default();
+        template_type.set_head(self.extract_template_string()?);
+        if self.prekind_is(TokenKind::NoSubstitutionTemplate) {
+            return Ok(template_type);
+        }
+
+        loop {
+            let span_begin = self.mark_begin();
+            let type_ = self.parse_type()?;
+            if !self.kind_is(TokenKind::TemplateMiddle) && !self.kind_is(TokenKind::TemplateTail) {
+                return Err(self.expect_error("Template Literal Type", "} of ${...}"));
+            }
+            let literal = self.extract_template_string()?;
+            template_type.push_template_span(ASTNode::new(
+                TemplateTypeSpan::new(type_, literal),
+                Span::new(span_begin, self.mark_end()),
+            ));
+
+            if self.prekind_is(TokenKind::TemplateTail) {
+                break;
+            }
+        }
+
+        Ok(template_type)
     }
 
+    /*
+    tupleElementTypes: (tupleElement (',' tupleElement)* ','?)?;
+    tupleElement: '...' type_ | type_ '?'?;
+    */
     fn parse_tuple_type(&mut self) -> ParseResult<TupleElementTypes> {
-        todo!()
+        let mut tuple_type = TupleElementTypes::default();
+        while !self.kind_is(TokenKind::RightBrace) {
+            let begin = self.mark_begin();
+            let ellipsis = self.kind_is(TokenKind::Ellipsis);
+            if ellipsis {
+                self.forward();
+            }
+
+            let mut tuple_element = TupleElement::new(self.parse_type()?);
+            if ellipsis {
+                tuple_element.set_ellipsis();
+            } else if self.kind_is(TokenKind::QuestionMark) {
+                self.forward();
+                tuple_element.set_question_mark();
+            }
+            tuple_type.push_tuple_element(ASTNode::new(
+                tuple_element,
+                Span::new(begin, self.mark_end()),
+            ));
+
+            if !self.kind_is(TokenKind::RightBrace) {
+                self.eat(TokenKind::Comma)?;
+            }
+        }
+        Ok(tuple_type)
     }
 
     /*
@@ -2118,6 +2807,11 @@ impl Parser {
         let begin = self.mark_begin();
 
         let type_;
+        let mut type_paras = None;
+        if self.kind_is(TokenKind::LessThan) {
+            type_paras = Some(self.parse_type_paras()?);
+        }
+
         let mut para_list = None;
         self.eat(TokenKind::LeftParen)?;
         if !self.kind_is(TokenKind::RightParen) {
@@ -2127,7 +2821,11 @@ impl Parser {
         self.eat(TokenKind::Arrow)?;
         type_ = self.parse_type()?;
 
-        Ok(FunctionType::new(para_list, type_))
+        let mut func_type = FunctionType::new(para_list, type_);
+        if let Some(type_paras) = type_paras {
+            func_type.set_type_paras(type_paras);
+        }
+        Ok(func_type)
     }
 
     fn parse_decorators(&mut self) -> ParseResult<ASTNode<Decorators>> {
@@ -2264,10 +2962,10 @@ impl Parser {
             TokenKind::LeftBrace => {
                 Ok(ASTNode::new(TypeMember::IndexSig(self.parse_index_sig()?), Span::new(begin, self.mark_end())))
             }
-            TokenKind::KeyWord(KeyWordKind::ReadOnly) => {
+            TokenKind::KeyWord(KeyWordKind::ReadOnly) if self.next_kind().is_identifier_name() => {
                 Ok(ASTNode::new(TypeMember::PropertySig(self.parse_property_sig()?), Span::new(begin, self.mark_end())))
             }
-            TokenKind::Identifier => {
+            _ if self.is_identifier_name() => {
                 // attention: do not exchange the order of this if below
                 // because property_sig can be the prefix of the method_sig
                 if let Some(method_sig) = self.try_to(Parser::parse_method_sig) {
@@ -2333,7 +3031,7 @@ impl Parser {
     fn parse_enum_member(&mut self) -> ParseResult<ASTNode<EnumMember>> {
         let begin = self.mark_begin();
         let mut enum_member = EnumMember::default();
-        enum_member.set_enum_member_name(self.parse_identifier()?);
+        enum_member.set_enum_member_name(self.parse_identifier_name()?);
 
         if self.kind_is(TokenKind::Assign) {
             enum_member.set_initializer(self.parse_initializer()?);
@@ -2370,7 +3068,8 @@ impl Parser {
             var_stat.set_var_modifier(var_modifier);
         }
 
-        if self.kind_is(TokenKind::KeyWord(KeyWordKind::ReadOnly)) {
+        // readonly 只有在同一行紧跟着标识符时才是修饰符, 例如 let readonly = 1;
+        if self.kind_is(TokenKind::KeyWord(KeyWordKind::ReadOnly)) && self.is_contextual_decl() {
             var_stat.set_readonly();
             self.forward();
         }
@@ -2456,4 +3155,25 @@ impl Parser {
             Span::new(begin, self.mark_end()),
         ))
     }
+
+    // 属性名, 方法名等位置上可以使用任意关键字
+    fn parse_identifier_name(&mut self) -> ParseResult<ASTNode<Identifier>> {
+        let begin = self.mark_begin();
+        Ok(ASTNode::new(
+            Identifier::new(&self.extact_identifier_name()?),
+            Span::new(begin, self.mark_end()),
+        ))
+    }
+
+    // 类成员名以及 . 之后的属性名, 除了任意关键字之外还可以是私有名 #name
+    fn parse_member_name(&mut self) -> ParseResult<ASTNode<Identifier>> {
+        if !self.kind_is(TokenKind::PrivateIdentifier) {
+            return self.parse_identifier_name();
+        }
+
+        let begin = self.mark_begin();
+        let identifier = Identifier::new(self.peek().unwrap().peek_value());
+        self.forward();
+        Ok(ASTNode::new(identifier, Span::new(begin, self.mark_end())))
+    }
 }
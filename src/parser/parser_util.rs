@@ -47,6 +47,23 @@ impl Parser {
         }
     }
 
+    // 类型参数和类型实参结尾的 >, 词法分析时 >> >>> >= 等会被当成一个 token, 需要先拆出开头的 >
+    pub(super) fn eat_more_than(&mut self) -> ParseResult<()> {
+        let rest_kind = match self.peek_kind() {
+            TokenKind::RightShiftArithmetic => TokenKind::MoreThan,
+            TokenKind::RightShiftLogical => TokenKind::RightShiftArithmetic,
+            TokenKind::GreaterThanEquals => TokenKind::Assign,
+            TokenKind::RightShiftArithmeticAssign => TokenKind::GreaterThanEquals,
+            TokenKind::RightShiftLogicalAssign => TokenKind::RightShiftArithmeticAssign,
+            _ => return self.eat(TokenKind::MoreThan),
+        };
+
+        let rest = self.tokens[self.index].split_more_than(rest_kind);
+        self.tokens.insert(self.index + 1, rest);
+        self.forward();
+        Ok(())
+    }
+
     pub(super) fn is_eos(&mut self) -> bool {
         match self.peek_kind() {
         // 用分号可以  xxx; yyy
@@ -77,6 +94,8 @@ impl Parser {
                 token.peek_kind(),
                 TokenKind::String
                     | TokenKind::Number
+                    | TokenKind::BigInt
+                    | TokenKind::RegExp
                     | TokenKind::KeyWord(KeyWordKind::True)
                     | TokenKind::KeyWord(KeyWordKind::False)
                     | TokenKind::KeyWord(KeyWordKind::Null)
@@ -84,9 +103,69 @@ impl Parser {
         })
     }
 
+    pub(super) fn is_identifier(&self) -> bool {
+        self.peek_kind().is_identifier()
+    }
+
+    pub(super) fn is_identifier_name(&self) -> bool {
+        self.peek_kind().is_identifier_name()
+    }
+
+    // 语句开头的上下文关键字, 只有同一行紧跟着标识符或关键字时才是声明, 否则只是普通的标识符, 例如 type = 1
+    pub(super) fn is_contextual_decl(&self) -> bool {
+        match (self.tokens.get(self.index), self.tokens.get(self.index + 1)) {
+            (Some(current), Some(next)) => {
+                next.peek_kind().is_identifier_name()
+                    && next.peek_begin().line == current.peek_end().line
+            }
+            _ => false,
+        }
+    }
+
+    // async 只有在同一行紧跟着 function, (, < 或者标识符时才是修饰符, 否则只是普通的标识符, 例如 async(1)
+    pub(super) fn is_async_prefix(&self) -> bool {
+        match (self.tokens.get(self.index), self.tokens.get(self.index + 1)) {
+            (Some(current), Some(next)) => {
+                current.peek_kind() == TokenKind::KeyWord(KeyWordKind::Async)
+                    && next.peek_begin().line == current.peek_end().line
+                    && (next.peek_kind().is_identifier()
+                        || matches!(
+                            next.peek_kind(),
+                            TokenKind::KeyWord(KeyWordKind::Function)
+                                | TokenKind::LeftParen
+                                | TokenKind::LessThan
+                        ))
+            }
+            _ => false,
+        }
+    }
+
+    // 类成员前的 static async readonly 后面紧跟着 ( < = ; : ? 时是成员名而不是修饰符, 例如 static() {}
+    pub(super) fn is_member_modifier(&self) -> bool {
+        !matches!(
+            self.next_kind(),
+            TokenKind::LeftParen
+                | TokenKind::LessThan
+                | TokenKind::Assign
+                | TokenKind::SemiColon
+                | TokenKind::Colon
+                | TokenKind::QuestionMark
+                | TokenKind::RightBracket
+                | TokenKind::EOF
+        )
+    }
+
+    // 下一个 token 与当前 token 在同一行
+    pub(super) fn is_next_on_same_line(&self) -> bool {
+        match (self.tokens.get(self.index), self.tokens.get(self.index + 1)) {
+            (Some(current), Some(next)) => next.peek_begin().line == current.peek_end().line,
+            _ => false,
+        }
+    }
+
     // 注意，该函数在 extract 的同时也会 eat Token
     pub(super) fn extact_identifier(&mut self) -> ParseResult<String> {
-        if self.kind_is(TokenKind::Identifier) {
+        if self.is_identifier() {
             let ident = self.peek().unwrap().peek_value().to_string();
             self.forward();
             Ok(ident)
@@ -100,57 +179,35 @@ impl Parser {
         }
     }
 
+    // 注意，该函数在 extract 的同时也会 eat Token
+    pub(super) fn extact_identifier_name(&mut self) -> ParseResult<String> {
+        if self.is_identifier_name() {
+            let ident = self.peek().unwrap().peek_value().to_string();
+            self.forward();
+            Ok(ident)
+        } else {
+            Err(self.expect_error("Identifier Name", "Identifier or keyword"))
+        }
+    }
+
     // 注意，该函数在 extract 的同时也会 eat Token
     pub(super) fn extact_literal(&mut self) -> ParseResult<Literal> {
         let literal = match self.peek_kind() {
             TokenKind::String => Literal::String(self.peek().unwrap().peek_value().to_string()),
 
-            TokenKind::Number => {
-                let string_value = self.peek().unwrap().peek_value();
-                // 先处理小数
-                if string_value.starts_with("0.") {
-                    if let Ok(float) = string_value.parse::<f64>() {
-                        Literal::Number(float)
-                    } else {
-                        return Err(self.report_error("Unrecognized number"));
-                    }
-                }
-                // 处理其余进制
-                else if let Some(stripped) = string_value.strip_prefix('0') {
-                    Literal::Integer(match string_value.as_bytes() {
-                        // 只是单个 0
-                        [b'0'] => 0i32,
-
-                        // 特殊的八进制
-                        [b'0', b'0'..=b'7', _rest @ ..] => {
-                            i32::from_str_radix(stripped, 8).unwrap()
-                        }
-
-                        // 一般情况
-                        [f, s, rest @ ..] => {
-                            let rest = std::str::from_utf8(rest).unwrap();
-                            match (f, s) {
-                                (b'0', b'b' | b'B') => i32::from_str_radix(rest, 2).unwrap(),
-                                (b'0', b'o' | b'O') => i32::from_str_radix(rest, 8).unwrap(),
-                                (b'0', b'x' | b'X') => i32::from_str_radix(rest, 16).unwrap(),
-                                _ => return Err(self.report_error("Unrecognized number")),
-                            }
-                        }
-
-                        _ => unreachable!(),
-                    })
-                } else {
-                    // 再处理最常见的两种情况
-                    if let Ok(integer) = string_value.parse::<i32>() {
-                        Literal::Integer(integer)
-                    } else if let Ok(float) = string_value.parse::<f64>() {
-                        Literal::Number(float)
-                    } else {
-                        unreachable!()
-                    }
+            TokenKind::RegExp => {
+                // token 的值形如 /pattern/flags, flags 中不会出现 /
+                let regexp = self.peek().unwrap().peek_value();
+                let (pattern, flags) = regexp[1..].rsplit_once('/').unwrap();
+                Literal::RegExp {
+                    pattern: pattern.to_string(),
+                    flags: flags.to_string(),
                 }
             }
 
+            TokenKind::Number => Literal::from_number(self.peek().unwrap().peek_value()),
+            TokenKind::BigInt => Literal::from_bigint(self.peek().unwrap().peek_value()),
+
             TokenKind::KeyWord(KeyWordKind::True) => Literal::Boolean(true),
             TokenKind::KeyWord(KeyWordKind::False) => Literal::Boolean(false),
             TokenKind::KeyWord(KeyWordKind::Null) => Literal::Null,
@@ -192,7 +249,7 @@ impl Parser {
         if let (Some(current), Some(pre)) =
             (self.tokens.get(self.index), self.tokens.get(self.index - 1))
         {
-            current.peek_line() > pre.peek_line()
+            current.peek_begin().line > pre.peek_end().line
         } else {
             false
         }
@@ -0,0 +1,174 @@
+use crate::{
+    ast::{ast_node::pattern::*, ASTNode, Span},
+    lexer::token_kind::TokenKind,
+};
+
+use super::{ParseResult, Parser};
+
+impl Parser {
+    /*
+    声明和参数中的绑定目标:
+    bindingTarget: Identifier | bindingPattern;
+    */
+    pub(super) fn parse_binding_target(&mut self) -> ParseResult<ASTNode<BindingTarget>> {
+        self.parse_target(false)
+    }
+
+    // 解构赋值 [a, b] = [b, a] 的左侧, 其中的目标还可以是成员表达式
+    pub(super) fn parse_assign_pattern(&mut self) -> ParseResult<ASTNode<BindingPattern>> {
+        self.parse_binding_pattern(true)
+    }
+
+    // [...] = 或者 {...} =, 需要跳过整个括号才能知道是不是解构赋值
+    pub(super) fn is_assign_pattern(&self) -> bool {
+        if !self.kind_is(TokenKind::LeftBrace) && !self.kind_is(TokenKind::LeftBracket) {
+            return false;
+        }
+
+        let mut depth = 0;
+        let mut distance = 0;
+        loop {
+            match self.lookahead(distance) {
+                TokenKind::LeftBrace | TokenKind::LeftBracket | TokenKind::LeftParen => depth += 1,
+                TokenKind::RightBrace | TokenKind::RightBracket | TokenKind::RightParen => {
+                    depth -= 1
+                }
+                TokenKind::EOF => return false,
+                _ => {}
+            }
+            distance += 1;
+            if depth == 0 {
+                return self.lookahead(distance) == TokenKind::Assign;
+            }
+        }
+    }
+
+    fn parse_target(&mut self, assign: bool) -> ParseResult<ASTNode<BindingTarget>> {
+        let begin = self.mark_begin();
+
+        let target = match self.peek_kind() {
+            TokenKind::LeftBrace | TokenKind::LeftBracket => {
+                BindingTarget::BindingPattern(self.parse_binding_pattern(assign)?.ctx())
+            }
+            _ if assign => BindingTarget::Exp(Box::new(self.parse_single_exp()?.ctx())),
+            _ => BindingTarget::Identifier(self.parse_identifier()?.ctx()),
+        };
+
+        Ok(ASTNode::new(target, Span::new(begin, self.mark_end())))
+    }
+
+    /*
+    bindingPattern: arrayBindingPattern | objectBindingPattern;
+    */
+    fn parse_binding_pattern(&mut self, assign: bool) -> ParseResult<ASTNode<BindingPattern>> {
+        let begin = self.mark_begin();
+
+        let pattern = match self.peek_kind() {
+            TokenKind::LeftBrace => BindingPattern::ArrayPattern(self.parse_array_pattern(assign)?),
+            TokenKind::LeftBracket => {
+                BindingPattern::ObjectPattern(self.parse_object_pattern(assign)?)
+            }
+            _ => return Err(self.expect_error("Binding Pattern", "[ or {")),
+        };
+
+        Ok(ASTNode::new(pattern, Span::new(begin, self.mark_end())))
+    }
+
+    /*
+    arrayBindingPattern: '[' (bindingElement? ',')* (bindingElement | '...' bindingTarget)? ']';
+    */
+    fn parse_array_pattern(&mut self, assign: bool) -> ParseResult<ArrayPattern> {
+        let mut array_pattern = ArrayPattern::default();
+
+        self.eat(TokenKind::LeftBrace)?;
+        while !self.kind_is(TokenKind::RightBrace) {
+            let begin = self.mark_begin();
+            match self.peek_kind() {
+                // [, a]
+                TokenKind::Comma => {
+                    self.forward();
+                    array_pattern.push_element(ASTNode::new(
+                        ArrayPatternElement::Elision(Elision {}),
+                        Span::new(begin, begin),
+                    ));
+                }
+                // 剩余元素必须在最后
+                TokenKind::Ellipsis => {
+                    self.forward();
+                    array_pattern.set_rest(self.parse_target(assign)?);
+                    break;
+                }
+                _ => {
+                    let element = self.parse_binding_element(assign)?.ctx();
+                    array_pattern.push_element(ASTNode::new(
+                        ArrayPatternElement::BindingElement(element),
+                        Span::new(begin, self.mark_end()),
+                    ));
+                    if !self.kind_is(TokenKind::RightBrace) {
+                        self.eat(TokenKind::Comma)?;
+                    }
+                }
+            }
+        }
+        self.eat(TokenKind::RightBrace)?;
+
+        Ok(array_pattern)
+    }
+
+    /*
+    objectBindingPattern: '{' (bindingProperty (',' bindingProperty)* ','?)? ('...' bindingTarget)? '}';
+    bindingProperty: Identifier initializer? | propertyName ':' bindingElement;
+    */
+    fn parse_object_pattern(&mut self, assign: bool) -> ParseResult<ObjectPattern> {
+        let mut object_pattern = ObjectPattern::default();
+
+        self.eat(TokenKind::LeftBracket)?;
+        while !self.kind_is(TokenKind::RightBracket) {
+            if self.kind_is(TokenKind::Ellipsis) {
+                self.forward();
+                object_pattern.set_rest(self.parse_target(assign)?);
+                break;
+            }
+
+            let begin = self.mark_begin();
+            let property = match self.is_identifier() && !self.nextkind_is(TokenKind::Colon) {
+                // { a } 或者 { a = 1 }
+                true => {
+                    let mut single_name = SingleNameBinding::new(self.parse_identifier()?);
+                    if self.kind_is(TokenKind::Assign) {
+                        single_name.set_initializer(self.parse_initializer()?);
+                    }
+                    BindingProperty::SingleNameBinding(single_name)
+                }
+                false => {
+                    let property_name = self.parse_property_name()?;
+                    self.eat(TokenKind::Colon)?;
+                    BindingProperty::PropertyBinding(PropertyBinding::new(
+                        property_name,
+                        self.parse_binding_element(assign)?,
+                    ))
+                }
+            };
+            object_pattern.push_property(ASTNode::new(property, Span::new(begin, self.mark_end())));
+
+            if !self.kind_is(TokenKind::RightBracket) {
+                self.eat(TokenKind::Comma)?;
+            }
+        }
+        self.eat(TokenKind::RightBracket)?;
+
+        Ok(object_pattern)
+    }
+
+    // bindingElement: bindingTarget initializer?;
+    fn parse_binding_element(&mut self, assign: bool) -> ParseResult<ASTNode<BindingElement>> {
+        let begin = self.mark_begin();
+
+        let mut element = BindingElement::new(self.parse_target(assign)?);
+        if self.kind_is(TokenKind::Assign) {
+            element.set_initializer(self.parse_initializer()?);
+        }
+
+        Ok(ASTNode::new(element, Span::new(begin, self.mark_end())))
+    }
+}
@@ -56,7 +56,6 @@ impl<'a, 'b: 'a> SymbolWalker<'a> {
             Stat::ContinueStat(_) => todo!(),
             Stat::BreakStat(_) => todo!(),
             Stat::ReturnStat(_) => todo!(),
-            Stat::YieldStat(_) => todo!(),
             Stat::WithStat(_) => todo!(),
             Stat::SwitchStat(_) => todo!(),
             Stat::ThrowStat(_) => todo!(),
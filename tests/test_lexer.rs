@@ -1,10 +1,11 @@
 #![allow(warnings, unused)]
 
-use kts::compiler::Compiler;
+use kts::compiler::{Compiler, KeyWordKind, TextEdit, Token, TokenKind, TriviaKind};
 use kts::error::err_exit;
+use std::{env, fs, path::Path};
 
 fn test_lexer(filename: &str) {
-    let compiler = Compiler::new(filename);
+    let compiler = Compiler::new(filename).set_show_ast();
     let res = compiler.run();
     match res {
         Ok(_) => {}
@@ -15,4 +16,283 @@ fn test_lexer(filename: &str) {
 #[test]
 fn test01() {
     test_lexer("resource/lexer/01.ts");
+    test_lexer("resource/lexer/02.ts");
+    test_lexer("resource/lexer/03.ts");
+    test_lexer("resource/lexer/04.ts");
+    test_lexer("resource/lexer/05.ts");
+    test_lexer("resource/lexer/06.ts");
+    test_lexer("resource/lexer/07.ts");
+    test_lexer("resource/lexer/09.ts");
+}
+
+fn comments(trivia: &[kts::compiler::Trivia]) -> Vec<&str> {
+    trivia
+        .iter()
+        .filter(|t| t.is_comment())
+        .map(|t| t.peek_text())
+        .collect()
+}
+
+#[test]
+fn test_keep_trivia() {
+    let tokens = Compiler::new("resource/lexer/05.ts")
+        .set_keep_trivia()
+        .token_stream();
+    let find = |value: &str| -> &Token { tokens.iter().find(|t| t.peek_value() == value).unwrap() };
+
+    // 文件头的注释挂在第一个 token 的 leading 上
+    assert_eq!(tokens[0].peek_value(), "let");
+    assert_eq!(
+        comments(tokens[0].peek_leading_trivia()),
+        [
+            "/*\n * Copyright (c) KTS contributors. 许可证头\n */",
+            "// @ts-ignore"
+        ]
+    );
+    assert!(comments(tokens[0].peek_trailing_trivia()).is_empty());
+
+    // 同一行的注释挂在前一个 token 的 trailing 上, 换行留给下一个 token
+    assert_eq!(tokens[4].peek_value(), ";");
+    assert_eq!(comments(tokens[4].peek_trailing_trivia()), ["// 行尾注释"]);
+    assert!(tokens[4]
+        .peek_trailing_trivia()
+        .iter()
+        .all(|t| !matches!(t.peek_kind(), TriviaKind::NewLine)));
+    assert_eq!(tokens[9].peek_value(), ";");
+    assert_eq!(
+        comments(tokens[9].peek_trailing_trivia()),
+        ["/* trailing block */", "/* another */"]
+    );
+
+    // JSDoc 挂在 function 的 leading 上, 并解析出描述和标签
+    let jsdoc = find("function")
+        .peek_leading_trivia()
+        .iter()
+        .find_map(|t| match t.peek_kind() {
+            TriviaKind::JsDoc(jsdoc) => Some(jsdoc),
+            _ => None,
+        })
+        .unwrap();
+    assert_eq!(jsdoc.peek_description(), "计算两个数的和\n支持多行描述");
+    let tags: Vec<_> = jsdoc
+        .peek_tags()
+        .iter()
+        .map(|tag| {
+            (
+                tag.peek_tag(),
+                tag.peek_type_expr(),
+                tag.peek_name(),
+                tag.peek_comment(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        tags,
+        [
+            ("param", Some("number"), Some("x"), "第一个数"),
+            ("param", Some("{a: number}"), Some("[y=1]"), "第二个数"),
+            ("returns", Some("number"), None, "和"),
+            ("deprecated", None, None, ""),
+        ]
+    );
+
+    // 空的块注释不是 JSDoc
+    let semi = tokens.iter().rev().find(|t| t.peek_value() == ";").unwrap();
+    assert!(matches!(
+        semi.peek_trailing_trivia()[1].peek_kind(),
+        TriviaKind::MultiLineComment
+    ));
+
+    // 不开启 keep_trivia 时不保留
+    let tokens = Compiler::new("resource/lexer/05.ts").token_stream();
+    assert!(tokens
+        .iter()
+        .all(|t| t.peek_leading_trivia().is_empty() && t.peek_trailing_trivia().is_empty()));
+}
+
+#[test]
+fn test_error_recovery() {
+    let src = fs::read_to_string("resource/lexer/08.ts").unwrap();
+    let err = Compiler::lex(&src).unwrap_err().to_string();
+    for line in 2..=6 {
+        assert!(err.contains(&format!("Line[{}]", line)), "{}", err);
+    }
+    assert_eq!(err.matches("LexerError").count(), 5, "{}", err);
+    // 未闭合的字符串报告在开头的引号处
+    assert!(
+        err.contains("Line[2], Column[9]: Unterminated string literal"),
+        "{}",
+        err
+    );
+}
+
+#[test]
+fn test_line_terminators() {
+    // \r\n 只算一次换行, \r U+2028 U+2029 也都是换行
+    let src = fs::read_to_string("resource/lexer/09.ts").unwrap() + "€";
+    let err = Compiler::lex(&src).unwrap_err().to_string();
+    assert!(err.contains("Line[10], Column[1]"), "{}", err);
+}
+
+#[test]
+fn test_long_line() {
+    // 列号是增量计算的, 很长的一行也不会让词法分析变成平方复杂度
+    let line = "let 变量 = 1; ".repeat(100_000);
+    let err = Compiler::lex(&(line.clone() + "€"))
+        .unwrap_err()
+        .to_string();
+    let column = line.chars().count() + 1;
+    assert!(
+        err.contains(&format!("Line[1], Column[{}]", column)),
+        "{}",
+        err
+    );
+}
+
+/*
+token 流快照测试: resource/lexer/xx.ts 的 token 流与 resource/lexer/xx.tokens 比较
+只有设置了环境变量 KTS_UPDATE_SNAPSHOT 时才重新生成快照, 快照不存在时测试失败
+*/
+fn check_snapshot(filename: &str) {
+    let dump = Compiler::new(filename).dump_tokens();
+    let snapshot = Path::new(filename).with_extension("tokens");
+    if env::var("KTS_UPDATE_SNAPSHOT").is_ok() {
+        fs::write(&snapshot, &dump).unwrap();
+        return;
+    }
+
+    let expected = fs::read_to_string(&snapshot).unwrap_or_else(|_| {
+        panic!(
+            "snapshot {} is missing, run with KTS_UPDATE_SNAPSHOT=1 to create it",
+            snapshot.display()
+        )
+    });
+    if expected != dump {
+        let diff: Vec<String> = expected
+            .lines()
+            .zip(dump.lines())
+            .enumerate()
+            .filter(|(_, (expected, actual))| expected != actual)
+            .map(|(i, (expected, actual))| format!("{}:\n- {}\n+ {}", i + 1, expected, actual))
+            .collect();
+        panic!(
+            "token stream of {} changed ({} -> {} lines):\n{}",
+            filename,
+            expected.lines().count(),
+            dump.lines().count(),
+            diff.join("\n")
+        );
+    }
+}
+
+#[test]
+fn test_token_snapshot() {
+    let mut files: Vec<String> = fs::read_dir("resource/lexer")
+        .unwrap()
+        .map(|entry| entry.unwrap().path().to_string_lossy().into_owned())
+        .filter(|path| path.ends_with(".ts") || path.ends_with(".tsx"))
+        .collect();
+    files.sort();
+    for file in files {
+        check_snapshot(&file);
+    }
+}
+
+#[test]
+fn test_token_api() {
+    // 编辑器只能通过公开的接口读取 token 的类型和位置
+    let compiler = Compiler::new("edit.ts").set_keep_trivia();
+    let src = "let a = 1; // 注释\nconst s = `x`;\n";
+    let tokens = compiler.tokenize(src);
+    let kinds: Vec<TokenKind> = tokens.iter().map(|t| t.peek_kind()).collect();
+    assert_eq!(
+        kinds,
+        [
+            TokenKind::KeyWord(KeyWordKind::Let),
+            TokenKind::Identifier,
+            TokenKind::Assign,
+            TokenKind::Number,
+            TokenKind::SemiColon,
+            TokenKind::KeyWord(KeyWordKind::Const),
+            TokenKind::Identifier,
+            TokenKind::Assign,
+            TokenKind::NoSubstitutionTemplate,
+            TokenKind::SemiColon,
+            TokenKind::EOF,
+        ]
+    );
+
+    // (offset, line, column)
+    let pos = |pos: kts::compiler::Pos| (pos.get_offset(), pos.get_line(), pos.get_column());
+    assert_eq!(tokens[6].peek_line(), 2);
+    assert_eq!(pos(tokens[6].peek_begin()), (27, 2, 7));
+    assert_eq!(pos(tokens[6].peek_end()), (28, 2, 8));
+
+    // trivia 的位置, 列号按字符计数
+    let comment = tokens[4]
+        .peek_trailing_trivia()
+        .iter()
+        .find(|t| t.is_comment())
+        .unwrap();
+    assert_eq!(pos(comment.peek_span().get_begin()), (11, 1, 12));
+    assert_eq!(pos(comment.peek_span().get_end()), (20, 1, 17));
+
+    // 把 a 改名为 total 之后, 之后的 token 都平移了 4 个字节
+    let edit = TextEdit::new(4..5, "total");
+    let edited = src.replacen(" a ", " total ", 1);
+    let tokens = compiler.relex(&edited, tokens, &edit);
+    assert_eq!(tokens[1].peek_value(), "total");
+    assert_eq!(pos(tokens[1].peek_end()), (9, 1, 10));
+    assert_eq!(pos(tokens[6].peek_begin()), (31, 2, 7));
+}
+
+/*
+增量词法分析测试: 对源码做一次编辑, 增量扫描的结果必须与对编辑之后的源码重新扫描的结果完全一致
+*/
+fn check_relex(compiler: &Compiler, src: &str, range: std::ops::Range<usize>, text: &str) {
+    let edited = format!("{}{}{}", &src[..range.start], text, &src[range.end..]);
+    let tokens = compiler.tokenize(src);
+    let relexed = compiler.relex(&edited, tokens, &TextEdit::new(range.clone(), text));
+    assert_eq!(
+        relexed,
+        compiler.tokenize(&edited),
+        "replace {:?} with {:?} in:\n{}",
+        range,
+        text,
+        src
+    );
+}
+
+#[test]
+fn test_relex() {
+    let compiler = Compiler::new("edit.ts");
+    let src = "let a = `x${ {b: 1}.b }y` + 1 /* c */ / 2;\nlet s = 'str';\n";
+    check_relex(&compiler, src, 4..5, "abc");
+    check_relex(&compiler, src, 8..9, "");
+    check_relex(&compiler, src, 30..30, "*");
+    check_relex(&compiler, src, 0..src.len(), "");
+    check_relex(&compiler, src, src.len()..src.len(), "`");
+
+    // 在每个字符边界上插入或删除可能改变后续扫描状态的内容, .ts 文件同时检查保留 trivia 的情况
+    for entry in fs::read_dir("resource/lexer").unwrap() {
+        let path = entry.unwrap().path().to_string_lossy().into_owned();
+        let compilers = match path.rsplit_once('.') {
+            Some((_, "ts")) => vec![Compiler::new(&path), Compiler::new(&path).set_keep_trivia()],
+            Some((_, "tsx")) => vec![Compiler::new(&path)],
+            _ => continue,
+        };
+        // 偏移不包括 BOM
+        let src = fs::read_to_string(&path).unwrap();
+        let src = src.trim_start_matches('\u{feff}');
+        let boundaries: Vec<usize> = src.char_indices().map(|(i, _)| i).collect();
+        for compiler in &compilers {
+            for &i in &boundaries {
+                for text in ["x", "\n", "`", "}", "/*", "'", "/", "<a>"] {
+                    check_relex(compiler, src, i..i, text);
+                }
+                let next = src[i..].chars().next().map_or(i, |c| i + c.len_utf8());
+                check_relex(compiler, src, i..next, "");
+            }
+        }
+    }
 }
@@ -10,6 +10,13 @@ fn test_parser(filename: &str) {
     }
 }
 
+// 必须报告语法错误, 并且错误信息中包含 expected
+fn test_parser_error(filename: &str, expected: &str) {
+    let compiler = Compiler::new(filename).set_show_ast();
+    let err = compiler.run().unwrap_err().to_string();
+    assert!(err.contains(expected), "{}", err);
+}
+
 #[test]
 fn test_import() {
     test_parser("resource/parser/import/01.ts");
@@ -33,6 +40,7 @@ fn test_class() {
     test_parser("resource/parser/class/07.ts");
     test_parser("resource/parser/class/08.ts");
     test_parser("resource/parser/class/09.ts");
+    test_parser("resource/parser/class/10.ts");
 }
 
 #[test]
@@ -54,6 +62,8 @@ fn test_iter() {
     test_parser("resource/parser/iter/for/01.ts");
     test_parser("resource/parser/iter/forvar/01.ts");
     test_parser("resource/parser/iter/forin/01.ts");
+    test_parser("resource/parser/iter/forin/02.ts");
+    test_parser("resource/parser/iter/forof/01.ts");
 }
 
 #[test]
@@ -66,15 +76,56 @@ fn test_exp() {
     test_parser("resource/parser/exp/06.ts");
     test_parser("resource/parser/exp/07.ts");
     test_parser("resource/parser/exp/08.ts");
+    test_parser("resource/parser/exp/09.ts");
+    test_parser("resource/parser/exp/10.ts");
+    test_parser("resource/parser/exp/11.ts");
+    test_parser("resource/parser/exp/12.ts");
+    test_parser("resource/parser/exp/13.ts");
+    test_parser("resource/parser/exp/14.ts");
+    test_parser("resource/parser/exp/15.ts");
 }
 
 #[test]
 fn test_var_decl() {
     test_parser("resource/parser/var_decl/01.ts");
     test_parser("resource/parser/var_decl/02.ts");
+    test_parser("resource/parser/var_decl/03.ts");
+    test_parser("resource/parser/var_decl/04.ts");
+}
+
+#[test]
+fn test_type() {
+    test_parser("resource/parser/type/01.ts");
+    test_parser("resource/parser/type/02.ts");
+    test_parser("resource/parser/type/03.ts");
+}
+
+#[test]
+fn test_try() {
+    test_parser("resource/parser/try/01.ts");
+    test_parser_error(
+        "resource/parser/try/02.ts",
+        "line break is not allowed after throw",
+    );
+    test_parser_error("resource/parser/try/03.ts", "Expect [catch or finally]");
+}
+
+#[test]
+fn test_generator() {
+    test_parser("resource/parser/generator/01.ts");
+}
+
+#[test]
+fn test_async() {
+    test_parser("resource/parser/async/01.ts");
 }
 
 #[test]
 fn test_temp() {
     test_parser("resource/parser/ztemp/temp.ts");
 }
+
+#[test]
+fn test_jsx() {
+    test_parser("resource/parser/jsx/01.tsx");
+}